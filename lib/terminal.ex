@@ -11,6 +11,20 @@ defmodule Terminal do
   @spec event_available?(pos_integer()) :: {:ok, boolean()} | {:error, binary()}
   defdelegate event_available?(milliseconds), to: Terminal.Native, as: :terminal_event_available?
 
+  @spec color_support(reference()) :: :truecolor | :ansi256 | :ansi16 | :none
+  defdelegate color_support(terminal), to: Terminal.Native, as: :terminal_color_support
+
+  defdelegate set_color_support(terminal, color_support),
+    to: Terminal.Native,
+    as: :terminal_set_color_support
+
+  @doc """
+  Draws a built widget resource into `area`, either `{chunks, index}` or
+  `{x, y, width, height}`. Lists, tables and scrollbars are passed with their
+  state as `{widget, state}`.
+  """
+  defdelegate render(terminal, widget, area), to: Terminal.Native
+
   def draw(terminal, f) do
     :ok = try_draw(terminal, f)
     nil
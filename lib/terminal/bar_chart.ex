@@ -0,0 +1,25 @@
+defmodule Terminal.BarChart do
+  defdelegate new(block), to: Terminal.Native, as: :bar_chart_new
+
+  defdelegate data(bar_chart, groups), to: Terminal.Native, as: :bar_chart_data
+
+  defdelegate max(bar_chart, max), to: Terminal.Native, as: :bar_chart_max
+
+  defdelegate bar_width(bar_chart, width), to: Terminal.Native, as: :bar_chart_bar_width
+
+  defdelegate bar_gap(bar_chart, gap), to: Terminal.Native, as: :bar_chart_bar_gap
+
+  defdelegate group_gap(bar_chart, gap), to: Terminal.Native, as: :bar_chart_group_gap
+
+  defdelegate bar_style(bar_chart, style), to: Terminal.Native, as: :bar_chart_bar_style
+
+  defdelegate value_style(bar_chart, style), to: Terminal.Native, as: :bar_chart_value_style
+
+  defdelegate label_style(bar_chart, style), to: Terminal.Native, as: :bar_chart_label_style
+
+  defdelegate direction(bar_chart, direction), to: Terminal.Native, as: :bar_chart_direction
+
+  defdelegate render(terminal, bar_chart, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :bar_chart_render
+end
@@ -2,11 +2,28 @@ defmodule Terminal.Block do
   defdelegate new, to: Terminal.Native, as: :block_new
   # def block_new(), do: :erlang.nif_error(:nif_not_loaded)
 
-  defdelegate borders(block), to: Terminal.Native, as: :block_borders
+  def borders(block, sides \\ :all) do
+    Terminal.Native.block_borders(block, sides)
+  end
+
+  defdelegate border_type(block, border_type), to: Terminal.Native, as: :block_border_type
+
+  defdelegate border_set(block, set), to: Terminal.Native, as: :block_border_set
+
   # @doc false
   # def block_borders(_block), do: :erlang.nif_error(:nif_not_loaded)
 
   # @doc false
   # def block_title(_block, _title), do: :erlang.nif_error(:nif_not_loaded)
-  defdelegate title(block, title), to: Terminal.Native, as: :block_title
+  def title(block, title, options \\ []) do
+    Terminal.Native.block_title(block, title, options)
+  end
+
+  defdelegate padding(block, padding), to: Terminal.Native, as: :block_padding
+
+  defdelegate style(block, style), to: Terminal.Native, as: :block_style
+
+  defdelegate border_style(block, style), to: Terminal.Native, as: :block_border_style
+
+  defdelegate title_style(block, style), to: Terminal.Native, as: :block_title_style
 end
@@ -0,0 +1,21 @@
+defmodule Terminal.Calendar do
+  defdelegate new(block, display_date, events), to: Terminal.Native, as: :calendar_new
+
+  defdelegate show_month_header(calendar, style),
+    to: Terminal.Native,
+    as: :calendar_show_month_header
+
+  defdelegate show_weekdays_header(calendar, style),
+    to: Terminal.Native,
+    as: :calendar_show_weekdays_header
+
+  defdelegate show_surrounding(calendar, style),
+    to: Terminal.Native,
+    as: :calendar_show_surrounding
+
+  defdelegate default_style(calendar, style), to: Terminal.Native, as: :calendar_default_style
+
+  defdelegate render(terminal, calendar, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :calendar_render
+end
@@ -0,0 +1,17 @@
+defmodule Terminal.Canvas do
+  defdelegate new(block), to: Terminal.Native, as: :canvas_new
+
+  defdelegate x_bounds(canvas, bounds), to: Terminal.Native, as: :canvas_x_bounds
+
+  defdelegate y_bounds(canvas, bounds), to: Terminal.Native, as: :canvas_y_bounds
+
+  defdelegate marker(canvas, marker), to: Terminal.Native, as: :canvas_marker
+
+  defdelegate background_color(canvas, color), to: Terminal.Native, as: :canvas_background_color
+
+  defdelegate shapes(canvas, shapes), to: Terminal.Native, as: :canvas_shapes
+
+  defdelegate render(terminal, canvas, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :canvas_render
+end
@@ -0,0 +1,15 @@
+defmodule Terminal.Chart do
+  defdelegate new(block, datasets), to: Terminal.Native, as: :chart_new
+
+  defdelegate datasets(chart, datasets), to: Terminal.Native, as: :chart_datasets
+
+  defdelegate x_axis(chart, axis), to: Terminal.Native, as: :chart_x_axis
+
+  defdelegate y_axis(chart, axis), to: Terminal.Native, as: :chart_y_axis
+
+  defdelegate legend_position(chart, position), to: Terminal.Native, as: :chart_legend_position
+
+  defdelegate render(terminal, chart, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :chart_render
+end
@@ -0,0 +1,20 @@
+defmodule Terminal.Code do
+  @type language :: :elixir | :rust | :json | :diff
+
+  @doc """
+  Highlighted `source` as text, for anywhere text is accepted, e.g.
+  `Terminal.Paragraph.new(block, Terminal.Code.text(source, :elixir))` for
+  code with a border that can be scrolled.
+  """
+  @spec text(String.t(), language(), keyword()) ::
+          {:code, language(), String.t(), keyword()}
+  def text(source, language, options \\ []) do
+    {:code, language, source, options}
+  end
+
+  @spec render(reference(), String.t(), language(), reference(), non_neg_integer(), keyword()) ::
+          :ok | {:error, String.t()}
+  def render(terminal, source, language, chunks, chunks_index, options \\ []) do
+    Terminal.Native.code_render(terminal, source, language, chunks, chunks_index, options)
+  end
+end
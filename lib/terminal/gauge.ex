@@ -0,0 +1,19 @@
+defmodule Terminal.Gauge do
+  defdelegate new(block), to: Terminal.Native, as: :gauge_new
+
+  defdelegate ratio(gauge, ratio), to: Terminal.Native, as: :gauge_ratio
+
+  defdelegate percent(gauge, percent), to: Terminal.Native, as: :gauge_percent
+
+  defdelegate label(gauge, label), to: Terminal.Native, as: :gauge_label
+
+  defdelegate style(gauge, style), to: Terminal.Native, as: :gauge_style
+
+  defdelegate gauge_style(gauge, style), to: Terminal.Native, as: :gauge_gauge_style
+
+  defdelegate use_unicode(gauge, use_unicode), to: Terminal.Native, as: :gauge_use_unicode
+
+  defdelegate render(terminal, gauge, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :gauge_render
+end
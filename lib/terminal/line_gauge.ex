@@ -0,0 +1,19 @@
+defmodule Terminal.LineGauge do
+  defdelegate new(block), to: Terminal.Native, as: :line_gauge_new
+
+  defdelegate ratio(line_gauge, ratio), to: Terminal.Native, as: :line_gauge_ratio
+
+  defdelegate label(line_gauge, label), to: Terminal.Native, as: :line_gauge_label
+
+  defdelegate style(line_gauge, style), to: Terminal.Native, as: :line_gauge_style
+
+  defdelegate filled_style(line_gauge, style), to: Terminal.Native, as: :line_gauge_filled_style
+
+  defdelegate unfilled_style(line_gauge, style),
+    to: Terminal.Native,
+    as: :line_gauge_unfilled_style
+
+  defdelegate render(terminal, line_gauge, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :line_gauge_render
+end
@@ -0,0 +1,11 @@
+defmodule Terminal.List do
+  defdelegate new(block, items), to: Terminal.Native, as: :list_new
+
+  defdelegate highlight_symbol(list, symbol), to: Terminal.Native, as: :list_highlight_symbol
+
+  defdelegate highlight_style(list, style), to: Terminal.Native, as: :list_highlight_style
+
+  defdelegate render(terminal, list, state, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :list_render
+end
@@ -0,0 +1,17 @@
+defmodule Terminal.ListState do
+  defdelegate new, to: Terminal.Native, as: :list_state_new
+
+  defdelegate select(state, index), to: Terminal.Native, as: :list_state_select
+
+  defdelegate select_next(state), to: Terminal.Native, as: :list_state_select_next
+
+  defdelegate select_previous(state), to: Terminal.Native, as: :list_state_select_previous
+
+  defdelegate select_first(state), to: Terminal.Native, as: :list_state_select_first
+
+  defdelegate select_last(state), to: Terminal.Native, as: :list_state_select_last
+
+  defdelegate selected(state), to: Terminal.Native, as: :list_state_selected
+
+  defdelegate offset(state), to: Terminal.Native, as: :list_state_offset
+end
@@ -44,6 +44,13 @@ defmodule Terminal.Native do
   @doc false
   def terminal_flush_backend(_terminal), do: :erlang.nif_error(:nif_not_loaded)
 
+  @doc false
+  def terminal_color_support(_terminal), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def terminal_set_color_support(_terminal, _color_support),
+    do: :erlang.nif_error(:nif_not_loaded)
+
   # END TERMINAL #
 
   ####################################################
@@ -51,29 +58,494 @@ defmodule Terminal.Native do
   # BEGIN PARAGRAPH #
 
   @doc false
-  def paragraph_new(_block, _text), do: :erlang.nif_error(:nif_not_loaded)
+  def paragraph_new(_block, _text, _options), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def paragraph_scroll(_paragraph, _offset), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def paragraph_render(_terminal, _text, _chunks, _chunk_index, _options),
+    do: :erlang.nif_error(:nif_not_loaded)
 
   @doc false
-  def paragraph_render(_terminal, _text, _chunks, _chunk_index),
+  def paragraph_line_count(_text, _wrap, _width, _options),
     do: :erlang.nif_error(:nif_not_loaded)
 
   # END PARAGRAPH #
 
   ####################################################
 
+  # BEGIN CODE #
+
+  @doc false
+  def code_render(_terminal, _source, _language, _chunks, _chunk_index, _options),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  # END CODE #
+
+  ####################################################
+
+  # BEGIN RENDER #
+
+  @doc false
+  def render(_terminal, _widget, _area), do: :erlang.nif_error(:nif_not_loaded)
+
+  # END RENDER #
+
+  ####################################################
+
   # BEGIN BLOCK #
 
   @doc false
   def block_new(), do: :erlang.nif_error(:nif_not_loaded)
 
   @doc false
-  def block_borders(_block), do: :erlang.nif_error(:nif_not_loaded)
+  def block_borders(_block, _sides), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def block_border_type(_block, _border_type), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def block_border_set(_block, _set), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def block_title(_block, _title, _options), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def block_padding(_block, _padding), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def block_style(_block, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def block_border_style(_block, _style), do: :erlang.nif_error(:nif_not_loaded)
 
   @doc false
-  def block_title(_block, _title), do: :erlang.nif_error(:nif_not_loaded)
+  def block_title_style(_block, _style), do: :erlang.nif_error(:nif_not_loaded)
 
   # END BLOCK #
 
+  ####################################################
+
+  # BEGIN LIST #
+
+  @doc false
+  def list_new(_block, _items), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_highlight_symbol(_list, _symbol), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_highlight_style(_list, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_render(_terminal, _list, _state, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_state_new(), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_state_select(_state, _index), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_state_select_next(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_state_select_previous(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_state_select_first(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_state_select_last(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_state_selected(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def list_state_offset(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  # END LIST #
+
+  ####################################################
+
+  # BEGIN TABLE #
+
+  @doc false
+  def table_new(_block, _rows, _widths), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_header(_table, _header), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_footer(_table, _footer), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_column_spacing(_table, _spacing), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_row_highlight_style(_table, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_highlight_symbol(_table, _symbol), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_render(_terminal, _table, _state, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_state_new(), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_state_select(_state, _index), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_state_select_next(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_state_select_previous(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_state_select_first(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_state_select_last(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_state_selected(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def table_state_offset(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  # END TABLE #
+
+  ####################################################
+
+  # BEGIN TABS #
+
+  @doc false
+  def tabs_new(_block, _titles), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def tabs_select(_tabs, _index), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def tabs_divider(_tabs, _divider), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def tabs_style(_tabs, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def tabs_highlight_style(_tabs, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def tabs_render(_terminal, _tabs, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  # END TABS #
+
+  ####################################################
+
+  # BEGIN GAUGE #
+
+  @doc false
+  def gauge_new(_block), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def gauge_ratio(_gauge, _ratio), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def gauge_percent(_gauge, _percent), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def gauge_label(_gauge, _label), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def gauge_style(_gauge, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def gauge_gauge_style(_gauge, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def gauge_use_unicode(_gauge, _use_unicode), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def gauge_render(_terminal, _gauge, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def line_gauge_new(_block), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def line_gauge_ratio(_line_gauge, _ratio), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def line_gauge_label(_line_gauge, _label), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def line_gauge_style(_line_gauge, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def line_gauge_filled_style(_line_gauge, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def line_gauge_unfilled_style(_line_gauge, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def line_gauge_render(_terminal, _line_gauge, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  # END GAUGE #
+
+  ####################################################
+
+  # BEGIN SPARKLINE #
+
+  @doc false
+  def sparkline_new(_block, _data), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def sparkline_data(_sparkline, _data), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def sparkline_max(_sparkline, _max), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def sparkline_bar_set(_sparkline, _bar_set), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def sparkline_direction(_sparkline, _direction), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def sparkline_style(_sparkline, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def sparkline_render(_terminal, _sparkline, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  # END SPARKLINE #
+
+  ####################################################
+
+  # BEGIN BAR CHART #
+
+  @doc false
+  def bar_chart_new(_block), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_data(_bar_chart, _groups), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_max(_bar_chart, _max), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_bar_width(_bar_chart, _width), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_bar_gap(_bar_chart, _gap), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_group_gap(_bar_chart, _gap), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_bar_style(_bar_chart, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_value_style(_bar_chart, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_label_style(_bar_chart, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_direction(_bar_chart, _direction), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def bar_chart_render(_terminal, _bar_chart, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  # END BAR CHART #
+
+  ####################################################
+
+  # BEGIN CHART #
+
+  @doc false
+  def chart_new(_block, _datasets), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def chart_datasets(_chart, _datasets), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def chart_x_axis(_chart, _axis), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def chart_y_axis(_chart, _axis), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def chart_legend_position(_chart, _position), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def chart_render(_terminal, _chart, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  # END CHART #
+
+  ####################################################
+
+  # BEGIN CANVAS #
+
+  @doc false
+  def canvas_new(_block), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def canvas_x_bounds(_canvas, _bounds), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def canvas_y_bounds(_canvas, _bounds), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def canvas_marker(_canvas, _marker), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def canvas_background_color(_canvas, _color), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def canvas_shapes(_canvas, _shapes), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def canvas_render(_terminal, _canvas, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  # END CANVAS #
+
+  ####################################################
+
+  # BEGIN SCROLLBAR #
+
+  @doc false
+  def scrollbar_new(_orientation), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_symbols(_scrollbar, _symbols), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_thumb_symbol(_scrollbar, _symbol), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_track_symbol(_scrollbar, _symbol), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_begin_symbol(_scrollbar, _symbol), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_end_symbol(_scrollbar, _symbol), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_style(_scrollbar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_thumb_style(_scrollbar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_track_style(_scrollbar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_begin_style(_scrollbar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_end_style(_scrollbar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_render(_terminal, _scrollbar, _state, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_new(_content_length), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_content_length(_state, _content_length),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_viewport_content_length(_state, _viewport_content_length),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_position(_state, _position), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_next(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_prev(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_first(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_last(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def scrollbar_state_get_position(_state), do: :erlang.nif_error(:nif_not_loaded)
+
+  # END SCROLLBAR #
+
+  ####################################################
+
+  # BEGIN CALENDAR #
+
+  @doc false
+  def calendar_new(_block, _display_date, _events), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def calendar_show_month_header(_calendar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def calendar_show_weekdays_header(_calendar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def calendar_show_surrounding(_calendar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def calendar_default_style(_calendar, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def calendar_render(_terminal, _calendar, _chunks, _chunk_index),
+    do: :erlang.nif_error(:nif_not_loaded)
+
+  # END CALENDAR #
+
+  ####################################################
+
+  # BEGIN THEME #
+
+  @doc false
+  def theme_new(_styles), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def theme_put(_theme, _name, _style), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def theme_activate(_theme), do: :erlang.nif_error(:nif_not_loaded)
+
+  # END THEME #
+
+  ####################################################
+
+  # BEGIN UNICODE #
+
+  @doc false
+  def unicode_width(_text), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def unicode_truncate(_text, _max_width, _ellipsis), do: :erlang.nif_error(:nif_not_loaded)
+
+  @doc false
+  def unicode_pad(_text, _target_width, _alignment), do: :erlang.nif_error(:nif_not_loaded)
+
+  # END UNICODE #
+
   # BEGIN CHUNKS #
 
   def chunks_new(_terminal, _constraints), do: :erlang.nif_error(:nif_not_loaded)
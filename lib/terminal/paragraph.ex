@@ -1,7 +1,22 @@
 defmodule Terminal.Paragraph do
-  defdelegate new(block, text), to: Terminal.Native, as: :paragraph_new
+  def new(block, text, options \\ []) do
+    Terminal.Native.paragraph_new(block, text, options)
+  end
 
-  defdelegate render(terminal, text, chunks, chunks_index),
-    to: Terminal.Native,
-    as: :paragraph_render
+  defdelegate scroll(paragraph, offset), to: Terminal.Native, as: :paragraph_scroll
+
+  @doc """
+  Returns `{line_count, line_width}` for `text` wrapped at `width`, which is
+  either a number of cells or a `{chunks, index}` layout slot.
+
+  Pass the paragraph's block as `block: block` to measure the text inside its
+  borders and padding; the block is not consumed.
+  """
+  def line_count(text, wrap, width, options \\ []) do
+    Terminal.Native.paragraph_line_count(text, wrap, width, options)
+  end
+
+  def render(terminal, text, chunks, chunks_index, options \\ []) do
+    Terminal.Native.paragraph_render(terminal, text, chunks, chunks_index, options)
+  end
 end
@@ -0,0 +1,27 @@
+defmodule Terminal.Scrollbar do
+  defdelegate new(orientation), to: Terminal.Native, as: :scrollbar_new
+
+  defdelegate symbols(scrollbar, symbols), to: Terminal.Native, as: :scrollbar_symbols
+
+  defdelegate thumb_symbol(scrollbar, symbol), to: Terminal.Native, as: :scrollbar_thumb_symbol
+
+  defdelegate track_symbol(scrollbar, symbol), to: Terminal.Native, as: :scrollbar_track_symbol
+
+  defdelegate begin_symbol(scrollbar, symbol), to: Terminal.Native, as: :scrollbar_begin_symbol
+
+  defdelegate end_symbol(scrollbar, symbol), to: Terminal.Native, as: :scrollbar_end_symbol
+
+  defdelegate style(scrollbar, style), to: Terminal.Native, as: :scrollbar_style
+
+  defdelegate thumb_style(scrollbar, style), to: Terminal.Native, as: :scrollbar_thumb_style
+
+  defdelegate track_style(scrollbar, style), to: Terminal.Native, as: :scrollbar_track_style
+
+  defdelegate begin_style(scrollbar, style), to: Terminal.Native, as: :scrollbar_begin_style
+
+  defdelegate end_style(scrollbar, style), to: Terminal.Native, as: :scrollbar_end_style
+
+  defdelegate render(terminal, scrollbar, state, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :scrollbar_render
+end
@@ -0,0 +1,23 @@
+defmodule Terminal.ScrollbarState do
+  defdelegate new(content_length), to: Terminal.Native, as: :scrollbar_state_new
+
+  defdelegate content_length(state, content_length),
+    to: Terminal.Native,
+    as: :scrollbar_state_content_length
+
+  defdelegate viewport_content_length(state, viewport_content_length),
+    to: Terminal.Native,
+    as: :scrollbar_state_viewport_content_length
+
+  defdelegate position(state, position), to: Terminal.Native, as: :scrollbar_state_position
+
+  defdelegate next(state), to: Terminal.Native, as: :scrollbar_state_next
+
+  defdelegate prev(state), to: Terminal.Native, as: :scrollbar_state_prev
+
+  defdelegate first(state), to: Terminal.Native, as: :scrollbar_state_first
+
+  defdelegate last(state), to: Terminal.Native, as: :scrollbar_state_last
+
+  defdelegate get_position(state), to: Terminal.Native, as: :scrollbar_state_get_position
+end
@@ -0,0 +1,17 @@
+defmodule Terminal.Sparkline do
+  defdelegate new(block, data), to: Terminal.Native, as: :sparkline_new
+
+  defdelegate data(sparkline, data), to: Terminal.Native, as: :sparkline_data
+
+  defdelegate max(sparkline, max), to: Terminal.Native, as: :sparkline_max
+
+  defdelegate bar_set(sparkline, bar_set), to: Terminal.Native, as: :sparkline_bar_set
+
+  defdelegate direction(sparkline, direction), to: Terminal.Native, as: :sparkline_direction
+
+  defdelegate style(sparkline, style), to: Terminal.Native, as: :sparkline_style
+
+  defdelegate render(terminal, sparkline, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :sparkline_render
+end
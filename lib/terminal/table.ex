@@ -0,0 +1,19 @@
+defmodule Terminal.Table do
+  defdelegate new(block, rows, widths), to: Terminal.Native, as: :table_new
+
+  defdelegate header(table, header), to: Terminal.Native, as: :table_header
+
+  defdelegate footer(table, footer), to: Terminal.Native, as: :table_footer
+
+  defdelegate column_spacing(table, spacing), to: Terminal.Native, as: :table_column_spacing
+
+  defdelegate row_highlight_style(table, style),
+    to: Terminal.Native,
+    as: :table_row_highlight_style
+
+  defdelegate highlight_symbol(table, symbol), to: Terminal.Native, as: :table_highlight_symbol
+
+  defdelegate render(terminal, table, state, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :table_render
+end
@@ -0,0 +1,15 @@
+defmodule Terminal.Tabs do
+  defdelegate new(block, titles), to: Terminal.Native, as: :tabs_new
+
+  defdelegate select(tabs, index), to: Terminal.Native, as: :tabs_select
+
+  defdelegate divider(tabs, divider), to: Terminal.Native, as: :tabs_divider
+
+  defdelegate style(tabs, style), to: Terminal.Native, as: :tabs_style
+
+  defdelegate highlight_style(tabs, style), to: Terminal.Native, as: :tabs_highlight_style
+
+  defdelegate render(terminal, tabs, chunks, chunks_index),
+    to: Terminal.Native,
+    as: :tabs_render
+end
@@ -0,0 +1,26 @@
+defmodule Terminal.Theme do
+  @moduledoc """
+  Named styles. Once a theme is activated, an atom such as `:error` can be
+  passed anywhere a style is expected.
+
+  Styles given to a widget's own setters (`Terminal.List.highlight_style/2`,
+  `Terminal.Calendar.show_month_header/2`, a paragraph's `:style` option,
+  ...), block styles and the default block title style keep their names and
+  are looked up again every time the widget is drawn. Switching between
+  light and dark themes is a single `activate/1`; widgets that are already
+  built pick it up on their next draw. If the new theme has no style by that
+  name, the widget keeps the style it had.
+
+  Styles inside text (spans, lines, titles, list items, table cells) and in
+  widget data (chart datasets and axes, bars, calendar events, canvas labels)
+  are resolved once, when they are passed in. Rebuild those to restyle them.
+
+  The active theme is global to the VM and shared by every terminal.
+  """
+
+  defdelegate new(styles), to: Terminal.Native, as: :theme_new
+
+  defdelegate put(theme, name, style), to: Terminal.Native, as: :theme_put
+
+  defdelegate activate(theme), to: Terminal.Native, as: :theme_activate
+end
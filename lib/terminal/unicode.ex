@@ -0,0 +1,14 @@
+defmodule Terminal.Unicode do
+  @spec width(String.t()) :: non_neg_integer()
+  defdelegate width(text), to: Terminal.Native, as: :unicode_width
+
+  @spec truncate(String.t(), non_neg_integer(), String.t()) :: String.t()
+  def truncate(text, max_width, ellipsis \\ "…") do
+    Terminal.Native.unicode_truncate(text, max_width, ellipsis)
+  end
+
+  @spec pad(String.t(), non_neg_integer(), :left | :center | :right) :: String.t()
+  def pad(text, width, alignment \\ :left) do
+    Terminal.Native.unicode_pad(text, width, alignment)
+  end
+end
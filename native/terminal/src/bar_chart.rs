@@ -0,0 +1,304 @@
+use crate::terminal::nif_error;
+use ratatui::layout::Direction;
+use ratatui::widgets::{Bar, BarChart, BarGroup};
+use rustler::{Decoder, NifResult, ResourceArc, Term};
+use std::sync::Mutex;
+
+mod atoms {
+    rustler::atoms! {
+        label,
+        bars,
+        value,
+        style,
+        value_style,
+        text_value,
+
+        vertical,
+        horizontal,
+    }
+}
+
+pub(crate) struct BarChartResource(
+    pub Mutex<Option<BarChart<'static>>>,
+    pub crate::theme::Themed<BarChart<'static>>,
+);
+
+impl rustler::Resource for BarChartResource {}
+
+/// A bar is either `{label, value}` or a map with a required `:value` and
+/// optional `:label`, `:style`, `:value_style` and `:text_value`.
+struct BarTerm(Bar<'static>);
+
+impl Decoder<'_> for BarTerm {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if let Ok((label, value)) = term.decode::<(String, u64)>() {
+            return Ok(BarTerm(Bar::default().label(label.into()).value(value)));
+        }
+
+        if !term.is_map() {
+            return Err(nif_error!("bar must be `{label, value}` or a map"));
+        }
+
+        let value: u64 = crate::options::get(term, atoms::value())?
+            .ok_or_else(|| nif_error!("bar map must have a `:value`"))?;
+
+        let mut bar = Bar::default().value(value);
+
+        if let Some(label) = crate::options::get::<String>(term, atoms::label())? {
+            bar = bar.label(label.into());
+        }
+
+        if let Some(style) = crate::options::get::<crate::style::Style>(term, atoms::style())? {
+            bar = bar.style(style.0);
+        }
+
+        if let Some(style) = crate::options::get::<crate::style::Style>(term, atoms::value_style())?
+        {
+            bar = bar.value_style(style.0);
+        }
+
+        if let Some(text_value) = crate::options::get::<String>(term, atoms::text_value())? {
+            bar = bar.text_value(text_value);
+        }
+
+        Ok(BarTerm(bar))
+    }
+}
+
+/// A group is either a plain list of bars or `%{label: label, bars: bars}`.
+struct BarGroupTerm(BarGroup<'static>);
+
+impl Decoder<'_> for BarGroupTerm {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if term.is_list() {
+            let bars: Vec<BarTerm> = term.decode()?;
+            let bars: Vec<Bar> = bars.into_iter().map(|b| b.0).collect();
+            return Ok(BarGroupTerm(BarGroup::default().bars(&bars)));
+        }
+
+        if !term.is_map() {
+            return Err(nif_error!("bar group must be a list of bars or a map"));
+        }
+
+        let bars: Vec<BarTerm> = crate::options::get(term, atoms::bars())?
+            .ok_or_else(|| nif_error!("bar group map must have `:bars`"))?;
+        let bars: Vec<Bar> = bars.into_iter().map(|b| b.0).collect();
+
+        let mut group = BarGroup::default().bars(&bars);
+
+        if let Some(label) = crate::options::get::<String>(term, atoms::label())? {
+            group = group.label(label.into());
+        }
+
+        Ok(BarGroupTerm(group))
+    }
+}
+
+struct BarDirection(Direction);
+
+impl Decoder<'_> for BarDirection {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::vertical() {
+            Ok(BarDirection(Direction::Vertical))
+        } else if term == atoms::horizontal() {
+            Ok(BarDirection(Direction::Horizontal))
+        } else {
+            Err(nif_error!("direction must be `:vertical` or `:horizontal`"))
+        }
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+) -> NifResult<ResourceArc<BarChartResource>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, BarChart::block);
+
+    let bar_chart = BarChart::default().block(block);
+
+    Ok(ResourceArc::new(BarChartResource(
+        Mutex::new(Some(bar_chart)),
+        themed,
+    )))
+}
+
+/// Appends groups to the chart; ratatui has no way to remove them again.
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_data(
+    bar_chart: ResourceArc<BarChartResource>,
+    groups: Vec<BarGroupTerm>,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let mut inner = lock.take().expect("must be able to take in bar_chart_data");
+        for group in groups {
+            inner = inner.data(group.0);
+        }
+        *lock = Some(inner)
+    }
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_max(
+    bar_chart: ResourceArc<BarChartResource>,
+    max: u64,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in bar_chart_max");
+        *lock = Some(inner.max(max))
+    }
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_bar_width(
+    bar_chart: ResourceArc<BarChartResource>,
+    width: u16,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in bar_chart_bar_width");
+        *lock = Some(inner.bar_width(width))
+    }
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_bar_gap(
+    bar_chart: ResourceArc<BarChartResource>,
+    gap: u16,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in bar_chart_bar_gap");
+        *lock = Some(inner.bar_gap(gap))
+    }
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_group_gap(
+    bar_chart: ResourceArc<BarChartResource>,
+    gap: u16,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in bar_chart_group_gap");
+        *lock = Some(inner.group_gap(gap))
+    }
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_bar_style(
+    bar_chart: ResourceArc<BarChartResource>,
+    style: crate::style::Style,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in bar_chart_bar_style");
+        *lock = Some(inner.bar_style(style.0))
+    }
+
+    bar_chart.1.set("bar_style", &style, BarChart::bar_style);
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_value_style(
+    bar_chart: ResourceArc<BarChartResource>,
+    style: crate::style::Style,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in bar_chart_value_style");
+        *lock = Some(inner.value_style(style.0))
+    }
+
+    bar_chart
+        .1
+        .set("value_style", &style, BarChart::value_style);
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_label_style(
+    bar_chart: ResourceArc<BarChartResource>,
+    style: crate::style::Style,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in bar_chart_label_style");
+        *lock = Some(inner.label_style(style.0))
+    }
+
+    bar_chart
+        .1
+        .set("label_style", &style, BarChart::label_style);
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_direction(
+    bar_chart: ResourceArc<BarChartResource>,
+    direction: BarDirection,
+) -> ResourceArc<BarChartResource> {
+    {
+        let mut lock = bar_chart.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in bar_chart_direction");
+        *lock = Some(inner.direction(direction.0))
+    }
+
+    bar_chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn bar_chart_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    bar_chart: ResourceArc<BarChartResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    bar_chart.1.restyle(&bar_chart.0);
+    let bar_chart = bar_chart
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| nif_error!("bar chart has already been used"))?;
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(bar_chart, area);
+
+    Ok(())
+}
@@ -1,27 +1,135 @@
+use crate::terminal::nif_error;
 use ratatui::{
     style::{Color, Modifier, Style},
-    text::Span,
-    widgets::Borders,
+    symbols::border,
+    widgets::{Block, BorderType, Borders, Padding},
 };
-use rustler::ResourceArc;
+use rustler::{Decoder, NifResult, ResourceArc, Term};
 
-pub(crate) struct BlockResource<'a>(pub std::sync::Mutex<Option<ratatui::widgets::Block<'a>>>);
+mod atoms {
+    rustler::atoms! {
+        style,
+        alignment,
+        position,
+
+        all,
+        none,
+        top,
+        bottom,
+        left,
+        right,
+
+        plain,
+        rounded,
+        double,
+        thick,
+        quadrant_inside,
+        quadrant_outside,
+
+        top_left,
+        top_right,
+        bottom_left,
+        bottom_right,
+        vertical_left,
+        vertical_right,
+        horizontal_top,
+        horizontal_bottom,
+    }
+}
+
+/// Used for titles until `block_title_style` replaces it: the active theme's
+/// `:title` style, or bold cyan without one.
+pub(crate) fn default_title_style() -> Style {
+    crate::theme::lookup("title")
+        .unwrap_or_else(|| Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+}
+
+pub(crate) struct BlockResource<'a>(
+    pub std::sync::Mutex<Option<ratatui::widgets::Block<'a>>>,
+    pub crate::theme::Themed<ratatui::widgets::Block<'a>>,
+);
 
 impl rustler::Resource for BlockResource<'static> {
     const IMPLEMENTS_DESTRUCTOR: bool = false;
     const IMPLEMENTS_DOWN: bool = false;
 }
 
+impl BlockResource<'static> {
+    /// Takes the block for a widget to own, with the theme names its styles
+    /// came from. A block can only be given to one widget, so a second taker
+    /// gets an error rather than a panic.
+    pub(crate) fn take(&self) -> NifResult<(Block<'static>, crate::theme::Themed<Block<'static>>)> {
+        let block = self
+            .0
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| nif_error!("block has already been used by a widget"))?;
+
+        Ok((block, self.1.take()))
+    }
+}
+
 #[rustler::nif(schedule = "DirtyIo")]
 fn block_new() -> rustler::ResourceArc<BlockResource<'static>> {
-    rustler::ResourceArc::new(BlockResource(std::sync::Mutex::new(Some(
-        ratatui::widgets::Block::default(),
-    ))))
+    let themed = crate::theme::Themed::default();
+    themed.put(
+        "title_style",
+        Some(Box::new(|block: Block<'static>| {
+            block.title_style(default_title_style())
+        })),
+    );
+
+    rustler::ResourceArc::new(BlockResource(
+        std::sync::Mutex::new(Some(
+            ratatui::widgets::Block::default().title_style(default_title_style()),
+        )),
+        themed,
+    ))
+}
+
+/// `:all`, `:none`, a single side (`:top`, `:bottom`, `:left`, `:right`) or
+/// a list of sides.
+struct Sides(Borders);
+
+impl Decoder<'_> for Sides {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if term.is_list() {
+            let sides: Vec<Sides> = term.decode()?;
+            return Ok(Sides(
+                sides
+                    .into_iter()
+                    .fold(Borders::NONE, |acc, side| acc | side.0),
+            ));
+        }
+
+        let side: rustler::Atom = term.decode()?;
+
+        let named = [
+            (atoms::all(), Borders::ALL),
+            (atoms::none(), Borders::NONE),
+            (atoms::top(), Borders::TOP),
+            (atoms::bottom(), Borders::BOTTOM),
+            (atoms::left(), Borders::LEFT),
+            (atoms::right(), Borders::RIGHT),
+        ];
+
+        named
+            .into_iter()
+            .find(|(name, _)| *name == side)
+            .map(|(_, borders)| Sides(borders))
+            .ok_or_else(|| {
+                nif_error!(
+                    "borders must be `:all`, `:none`, `:top`, `:bottom`, `:left`, `:right` or a list of sides"
+                )
+            })
+    }
 }
 
 #[rustler::nif(schedule = "DirtyIo")]
 fn block_borders(
     block: rustler::ResourceArc<BlockResource<'static>>,
+    sides: Sides,
 ) -> rustler::ResourceArc<BlockResource<'static>> {
     {
         let mut lock = block
@@ -29,28 +137,270 @@ fn block_borders(
             .lock()
             .expect("must be able to take in blockborders");
         let inner = lock.take().unwrap();
-        *lock = Some(inner.borders(Borders::ALL))
+        *lock = Some(inner.borders(sides.0))
+    }
+
+    block
+}
+
+struct BorderTypeName(BorderType);
+
+impl Decoder<'_> for BorderTypeName {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        let named = [
+            (atoms::plain(), BorderType::Plain),
+            (atoms::rounded(), BorderType::Rounded),
+            (atoms::double(), BorderType::Double),
+            (atoms::thick(), BorderType::Thick),
+            (atoms::quadrant_inside(), BorderType::QuadrantInside),
+            (atoms::quadrant_outside(), BorderType::QuadrantOutside),
+        ];
+
+        named
+            .into_iter()
+            .find(|(name, _)| *name == term)
+            .map(|(_, border_type)| BorderTypeName(border_type))
+            .ok_or_else(|| {
+                nif_error!(
+                    "border type must be `:plain`, `:rounded`, `:double`, `:thick`, `:quadrant_inside` or `:quadrant_outside`"
+                )
+            })
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn block_border_type(
+    block: ResourceArc<BlockResource<'static>>,
+    border_type: BorderTypeName,
+) -> ResourceArc<BlockResource<'static>> {
+    {
+        let mut lock = block.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in block_border_type");
+        *lock = Some(inner.border_type(border_type.0))
     }
 
     block
 }
 
+/// A map or keyword list of border symbols (`:top_left`, `:horizontal_top`,
+/// `:vertical_left`, ...). Symbols that aren't given come from the plain set.
+struct BorderSet(border::Set);
+
+impl Decoder<'_> for BorderSet {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if !crate::options::is_options(term) {
+            return Err(nif_error!("border set must be a map or keyword list"));
+        }
+
+        let symbol = |key, default: &'static str| -> NifResult<&'static str> {
+            Ok(crate::options::get::<&str>(term, key)?.map_or(default, crate::intern::intern))
+        };
+
+        let plain = border::PLAIN;
+
+        Ok(BorderSet(border::Set {
+            top_left: symbol(atoms::top_left(), plain.top_left)?,
+            top_right: symbol(atoms::top_right(), plain.top_right)?,
+            bottom_left: symbol(atoms::bottom_left(), plain.bottom_left)?,
+            bottom_right: symbol(atoms::bottom_right(), plain.bottom_right)?,
+            vertical_left: symbol(atoms::vertical_left(), plain.vertical_left)?,
+            vertical_right: symbol(atoms::vertical_right(), plain.vertical_right)?,
+            horizontal_top: symbol(atoms::horizontal_top(), plain.horizontal_top)?,
+            horizontal_bottom: symbol(atoms::horizontal_bottom(), plain.horizontal_bottom)?,
+        }))
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn block_border_set(
+    block: ResourceArc<BlockResource<'static>>,
+    set: BorderSet,
+) -> ResourceArc<BlockResource<'static>> {
+    {
+        let mut lock = block.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in block_border_set");
+        *lock = Some(inner.border_set(set.0))
+    }
+
+    block
+}
+
+/// Style for the whole block area, filling its background.
+#[rustler::nif(schedule = "DirtyIo")]
+fn block_style(
+    block: ResourceArc<BlockResource<'static>>,
+    style: crate::style::Style,
+) -> ResourceArc<BlockResource<'static>> {
+    {
+        let mut lock = block.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in block_style");
+        *lock = Some(inner.style(style.0))
+    }
+
+    block.1.set("style", &style, Block::style);
+
+    block
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn block_border_style(
+    block: ResourceArc<BlockResource<'static>>,
+    style: crate::style::Style,
+) -> ResourceArc<BlockResource<'static>> {
+    {
+        let mut lock = block.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in block_border_style");
+        *lock = Some(inner.border_style(style.0))
+    }
+
+    block.1.set("border_style", &style, Block::border_style);
+
+    block
+}
+
+/// Style applied under every title; a title's own style is patched over it.
+#[rustler::nif(schedule = "DirtyIo")]
+fn block_title_style(
+    block: ResourceArc<BlockResource<'static>>,
+    style: crate::style::Style,
+) -> ResourceArc<BlockResource<'static>> {
+    {
+        let mut lock = block.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in block_title_style");
+        *lock = Some(inner.title_style(style.0))
+    }
+
+    block.1.set("title_style", &style, Block::title_style);
+
+    block
+}
+
+/// Padding inside the borders: a single number for every side, or a map or
+/// keyword list of `:top`, `:bottom`, `:left` and `:right` (missing sides are 0).
+struct PaddingTerm(Padding);
+
+impl Decoder<'_> for PaddingTerm {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if let Ok(n) = term.decode::<u16>() {
+            return Ok(PaddingTerm(Padding::uniform(n)));
+        }
+
+        if !crate::options::is_options(term) {
+            return Err(nif_error!(
+                "padding must be an integer, a map or a keyword list"
+            ));
+        }
+
+        let side = |key| -> NifResult<u16> { Ok(crate::options::get(term, key)?.unwrap_or(0)) };
+
+        Ok(PaddingTerm(Padding::new(
+            side(atoms::left())?,
+            side(atoms::right())?,
+            side(atoms::top())?,
+            side(atoms::bottom())?,
+        )))
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn block_padding(
+    block: ResourceArc<BlockResource<'static>>,
+    padding: PaddingTerm,
+) -> ResourceArc<BlockResource<'static>> {
+    {
+        let mut lock = block.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in block_padding");
+        *lock = Some(inner.padding(padding.0))
+    }
+
+    block
+}
+
+#[derive(Clone, Copy)]
+enum TitlePosition {
+    Top,
+    Bottom,
+}
+
+impl Decoder<'_> for TitlePosition {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::top() {
+            Ok(TitlePosition::Top)
+        } else if term == atoms::bottom() {
+            Ok(TitlePosition::Bottom)
+        } else {
+            Err(nif_error!("title position must be `:top` or `:bottom`"))
+        }
+    }
+}
+
+/// Options for `block_title/3`, a map or keyword list.
+struct TitleOptions {
+    style: Option<Style>,
+    alignment: Option<ratatui::layout::Alignment>,
+    position: TitlePosition,
+}
+
+impl Decoder<'_> for TitleOptions {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if !crate::options::is_options(term) {
+            return Err(nif_error!("title options must be a map or keyword list"));
+        }
+
+        let style = crate::options::get::<crate::style::Style>(term, atoms::style())?.map(|s| s.0);
+
+        let alignment =
+            crate::options::get::<crate::terminal::Alignment>(term, atoms::alignment())?
+                .map(|a| a.0);
+
+        let position = crate::options::get::<TitlePosition>(term, atoms::position())?
+            .unwrap_or(TitlePosition::Top);
+
+        Ok(TitleOptions {
+            style,
+            alignment,
+            position,
+        })
+    }
+}
+
+/// Adds a title; call it more than once for several titles, e.g. one on top
+/// and a key hint at the bottom right.
 #[rustler::nif(schedule = "DirtyIo")]
 fn block_title(
     block: ResourceArc<BlockResource<'static>>,
-    title: &str,
+    title: crate::text::Line,
+    options: TitleOptions,
 ) -> ResourceArc<BlockResource<'static>> {
     {
         let mut lock = block.0.lock().unwrap();
         let inner = lock.take().expect("must be able to take in blocktitle");
-        *lock = Some(
-            inner.title(Span::styled(
-                title.to_owned(),
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )),
-        )
+        let mut title = title.0;
+
+        if let Some(style) = options.style {
+            title = title.patch_style(style);
+        }
+
+        if let Some(alignment) = options.alignment {
+            title = title.alignment(alignment);
+        }
+
+        *lock = Some(match options.position {
+            TitlePosition::Top => inner.title_top(title),
+            TitlePosition::Bottom => inner.title_bottom(title),
+        })
     }
 
     block
@@ -0,0 +1,183 @@
+use crate::terminal::nif_error;
+use ratatui::widgets::calendar::{CalendarEventStore, Monthly};
+use rustler::{Decoder, NifResult, ResourceArc, Term};
+use std::sync::Mutex;
+
+mod atoms {
+    rustler::atoms! {
+        year,
+        month,
+        day,
+    }
+}
+
+pub(crate) struct CalendarResource(
+    pub Mutex<Option<Monthly<'static, CalendarEventStore>>>,
+    pub crate::theme::Themed<Monthly<'static, CalendarEventStore>>,
+);
+
+impl rustler::Resource for CalendarResource {}
+
+/// An Elixir `Date` struct.
+struct Date(time::Date);
+
+impl Decoder<'_> for Date {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let year: i32 = term.map_get(atoms::year())?.decode()?;
+        let month: u8 = term.map_get(atoms::month())?.decode()?;
+        let day: u8 = term.map_get(atoms::day())?.decode()?;
+
+        let month = time::Month::try_from(month).map_err(|e| nif_error!(e))?;
+
+        time::Date::from_calendar_date(year, month, day)
+            .map(Date)
+            .map_err(|e| nif_error!(e))
+    }
+}
+
+/// Either a map of `Date` to style or a list of `{date, style}` tuples.
+struct Events(CalendarEventStore);
+
+impl Decoder<'_> for Events {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let mut store = CalendarEventStore::default();
+
+        let events: Vec<(Date, crate::style::Style)> = if term.is_map() {
+            rustler::types::map::MapIterator::new(term)
+                .ok_or_else(|| nif_error!("events must be a map or a list"))?
+                .map(|(date, style)| Ok((date.decode()?, style.decode()?)))
+                .collect::<NifResult<_>>()?
+        } else {
+            term.decode()?
+        };
+
+        for (date, style) in events {
+            store.add(date.0, style.0);
+        }
+
+        Ok(Events(store))
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn calendar_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+    display_date: Date,
+    events: Events,
+) -> NifResult<ResourceArc<CalendarResource>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, Monthly::block);
+
+    let calendar = Monthly::new(display_date.0, events.0).block(block);
+
+    Ok(ResourceArc::new(CalendarResource(
+        Mutex::new(Some(calendar)),
+        themed,
+    )))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn calendar_show_month_header(
+    calendar: ResourceArc<CalendarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<CalendarResource> {
+    {
+        let mut lock = calendar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in calendar_show_month_header");
+        *lock = Some(inner.show_month_header(style.0))
+    }
+
+    calendar
+        .1
+        .set("show_month_header", &style, Monthly::show_month_header);
+
+    calendar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn calendar_show_weekdays_header(
+    calendar: ResourceArc<CalendarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<CalendarResource> {
+    {
+        let mut lock = calendar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in calendar_show_weekdays_header");
+        *lock = Some(inner.show_weekdays_header(style.0))
+    }
+
+    calendar.1.set(
+        "show_weekdays_header",
+        &style,
+        Monthly::show_weekdays_header,
+    );
+
+    calendar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn calendar_show_surrounding(
+    calendar: ResourceArc<CalendarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<CalendarResource> {
+    {
+        let mut lock = calendar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in calendar_show_surrounding");
+        *lock = Some(inner.show_surrounding(style.0))
+    }
+
+    calendar
+        .1
+        .set("show_surrounding", &style, Monthly::show_surrounding);
+
+    calendar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn calendar_default_style(
+    calendar: ResourceArc<CalendarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<CalendarResource> {
+    {
+        let mut lock = calendar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in calendar_default_style");
+        *lock = Some(inner.default_style(style.0))
+    }
+
+    calendar
+        .1
+        .set("default_style", &style, Monthly::default_style);
+
+    calendar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn calendar_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    calendar: ResourceArc<CalendarResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    calendar.1.restyle(&calendar.0);
+    let calendar = calendar
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| nif_error!("calendar has already been used"))?;
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(calendar, area);
+
+    Ok(())
+}
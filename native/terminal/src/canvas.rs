@@ -0,0 +1,229 @@
+use crate::terminal::nif_error;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::symbols;
+use ratatui::text::Span;
+use ratatui::widgets::canvas::{Canvas, Circle, Line, Points, Rectangle};
+use ratatui::widgets::{Block, Widget};
+use rustler::types::tuple::get_tuple;
+use rustler::{Decoder, NifResult, ResourceArc, Term};
+use std::sync::Mutex;
+
+mod atoms {
+    rustler::atoms! {
+        line,
+        rectangle,
+        circle,
+        points,
+        label,
+    }
+}
+
+enum Shape {
+    Line(Line),
+    Rectangle(Rectangle),
+    Circle(Circle),
+    Points(Vec<(f64, f64)>, Color),
+    Label(f64, f64, String, Option<ratatui::style::Style>),
+}
+
+/// Shapes are tagged tuples:
+///
+/// - `{:line, x1, y1, x2, y2, color}`
+/// - `{:rectangle, x, y, width, height, color}`
+/// - `{:circle, x, y, radius, color}`
+/// - `{:points, [{x, y}], color}`
+/// - `{:label, x, y, text}` or `{:label, x, y, text, style}`
+impl Decoder<'_> for Shape {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let elements = get_tuple(term)?;
+
+        let Some((tag, rest)) = elements.split_first() else {
+            return Err(nif_error!("shape must be a tagged tuple"));
+        };
+
+        let tag: rustler::Atom = tag.decode()?;
+
+        match rest {
+            [x1, y1, x2, y2, color] if tag == atoms::line() => Ok(Shape::Line(Line::new(
+                x1.decode()?,
+                y1.decode()?,
+                x2.decode()?,
+                y2.decode()?,
+                color.decode::<crate::color::Color>()?.0,
+            ))),
+            [x, y, width, height, color] if tag == atoms::rectangle() => {
+                Ok(Shape::Rectangle(Rectangle {
+                    x: x.decode()?,
+                    y: y.decode()?,
+                    width: width.decode()?,
+                    height: height.decode()?,
+                    color: color.decode::<crate::color::Color>()?.0,
+                }))
+            }
+            [x, y, radius, color] if tag == atoms::circle() => Ok(Shape::Circle(Circle {
+                x: x.decode()?,
+                y: y.decode()?,
+                radius: radius.decode()?,
+                color: color.decode::<crate::color::Color>()?.0,
+            })),
+            [coords, color] if tag == atoms::points() => Ok(Shape::Points(
+                coords.decode()?,
+                color.decode::<crate::color::Color>()?.0,
+            )),
+            [x, y, text] if tag == atoms::label() => {
+                Ok(Shape::Label(x.decode()?, y.decode()?, text.decode()?, None))
+            }
+            [x, y, text, style] if tag == atoms::label() => Ok(Shape::Label(
+                x.decode()?,
+                y.decode()?,
+                text.decode()?,
+                Some(style.decode::<crate::style::Style>()?.0),
+            )),
+            _ => Err(nif_error!(
+                "shape must be a `:line`, `:rectangle`, `:circle`, `:points` or `:label` tuple"
+            )),
+        }
+    }
+}
+
+pub(crate) struct CanvasSpec {
+    block: Block<'static>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    marker: symbols::Marker,
+    background_color: Color,
+    shapes: Vec<Shape>,
+}
+
+impl Widget for &CanvasSpec {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Canvas::default()
+            .block(self.block.clone())
+            .x_bounds(self.x_bounds)
+            .y_bounds(self.y_bounds)
+            .marker(self.marker)
+            .background_color(self.background_color)
+            .paint(|ctx| {
+                for shape in &self.shapes {
+                    match shape {
+                        Shape::Line(line) => ctx.draw(line),
+                        Shape::Rectangle(rectangle) => ctx.draw(rectangle),
+                        Shape::Circle(circle) => ctx.draw(circle),
+                        Shape::Points(coords, color) => ctx.draw(&Points {
+                            coords,
+                            color: *color,
+                        }),
+                        Shape::Label(x, y, text, style) => ctx.print(
+                            *x,
+                            *y,
+                            Span::styled(text.clone(), style.unwrap_or_default()),
+                        ),
+                    }
+                }
+            })
+            .render(area, buf);
+    }
+}
+
+/// The spec, and the theme names its block's styles came from.
+pub(crate) struct CanvasResource(
+    pub Mutex<CanvasSpec>,
+    pub crate::theme::Themed<Block<'static>>,
+);
+
+impl rustler::Resource for CanvasResource {}
+
+impl CanvasResource {
+    /// Locks the spec for drawing, with its block restyled against the
+    /// active theme.
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, CanvasSpec> {
+        let mut spec = self.0.lock().unwrap();
+        spec.block = self.1.apply(std::mem::take(&mut spec.block));
+        spec
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn canvas_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+) -> NifResult<ResourceArc<CanvasResource>> {
+    let (block, block_theme) = block.take()?;
+
+    Ok(ResourceArc::new(CanvasResource(
+        Mutex::new(CanvasSpec {
+            block,
+            x_bounds: [0.0, 0.0],
+            y_bounds: [0.0, 0.0],
+            marker: symbols::Marker::Braille,
+            background_color: Color::Reset,
+            shapes: vec![],
+        }),
+        block_theme,
+    )))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn canvas_x_bounds(
+    canvas: ResourceArc<CanvasResource>,
+    bounds: (f64, f64),
+) -> ResourceArc<CanvasResource> {
+    canvas.0.lock().unwrap().x_bounds = [bounds.0, bounds.1];
+    canvas
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn canvas_y_bounds(
+    canvas: ResourceArc<CanvasResource>,
+    bounds: (f64, f64),
+) -> ResourceArc<CanvasResource> {
+    canvas.0.lock().unwrap().y_bounds = [bounds.0, bounds.1];
+    canvas
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn canvas_marker(
+    canvas: ResourceArc<CanvasResource>,
+    marker: crate::chart::Marker,
+) -> ResourceArc<CanvasResource> {
+    canvas.0.lock().unwrap().marker = marker.0;
+    canvas
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn canvas_background_color(
+    canvas: ResourceArc<CanvasResource>,
+    color: crate::color::Color,
+) -> ResourceArc<CanvasResource> {
+    canvas.0.lock().unwrap().background_color = color.0;
+    canvas
+}
+
+/// Replaces every shape on the canvas.
+#[rustler::nif(schedule = "DirtyIo")]
+fn canvas_shapes(
+    canvas: ResourceArc<CanvasResource>,
+    shapes: Vec<Shape>,
+) -> ResourceArc<CanvasResource> {
+    canvas.0.lock().unwrap().shapes = shapes;
+    canvas
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn canvas_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    canvas: ResourceArc<CanvasResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    let spec = canvas.lock();
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(&*spec, area);
+
+    Ok(())
+}
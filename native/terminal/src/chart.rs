@@ -0,0 +1,303 @@
+use crate::terminal::nif_error;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::symbols;
+use ratatui::widgets::{Axis, Block, Chart, Dataset, GraphType, LegendPosition, Widget};
+use rustler::{Decoder, NifResult, ResourceArc, Term};
+use std::sync::Mutex;
+
+mod atoms {
+    rustler::atoms! {
+        name,
+        data,
+        marker,
+        graph_type,
+        style,
+
+        title,
+        bounds,
+        labels,
+        labels_alignment,
+
+        dot,
+        block,
+        bar,
+        braille,
+        half_block,
+
+        scatter,
+        line,
+
+        top,
+        top_right,
+        top_left,
+        left,
+        right,
+        bottom,
+        bottom_right,
+        bottom_left,
+    }
+}
+
+/// `Dataset` borrows its points, so the points are owned here and the
+/// dataset is only attached to them while rendering.
+struct DatasetSpec {
+    dataset: Dataset<'static>,
+    data: Vec<(f64, f64)>,
+}
+
+pub(crate) struct ChartSpec {
+    block: Block<'static>,
+    datasets: Vec<DatasetSpec>,
+    x_axis: Axis<'static>,
+    y_axis: Axis<'static>,
+    legend_position: Option<LegendPosition>,
+}
+
+impl Widget for &ChartSpec {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let datasets = self
+            .datasets
+            .iter()
+            .map(|d| d.dataset.clone().data(&d.data))
+            .collect();
+
+        Chart::new(datasets)
+            .block(self.block.clone())
+            .x_axis(self.x_axis.clone())
+            .y_axis(self.y_axis.clone())
+            .legend_position(self.legend_position)
+            .render(area, buf);
+    }
+}
+
+/// The spec, and the theme names its block's styles came from.
+pub(crate) struct ChartResource(
+    pub Mutex<ChartSpec>,
+    pub crate::theme::Themed<Block<'static>>,
+);
+
+impl rustler::Resource for ChartResource {}
+
+impl ChartResource {
+    /// Locks the spec for drawing, with its block restyled against the
+    /// active theme.
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, ChartSpec> {
+        let mut spec = self.0.lock().unwrap();
+        spec.block = self.1.apply(std::mem::take(&mut spec.block));
+        spec
+    }
+}
+
+pub(crate) struct Marker(pub symbols::Marker);
+
+impl Decoder<'_> for Marker {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::dot() {
+            Ok(Marker(symbols::Marker::Dot))
+        } else if term == atoms::block() {
+            Ok(Marker(symbols::Marker::Block))
+        } else if term == atoms::bar() {
+            Ok(Marker(symbols::Marker::Bar))
+        } else if term == atoms::braille() {
+            Ok(Marker(symbols::Marker::Braille))
+        } else if term == atoms::half_block() {
+            Ok(Marker(symbols::Marker::HalfBlock))
+        } else {
+            Err(nif_error!(
+                "marker must be `:dot`, `:block`, `:bar`, `:braille` or `:half_block`"
+            ))
+        }
+    }
+}
+
+struct GraphTypeTerm(GraphType);
+
+impl Decoder<'_> for GraphTypeTerm {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::line() {
+            Ok(GraphTypeTerm(GraphType::Line))
+        } else if term == atoms::scatter() {
+            Ok(GraphTypeTerm(GraphType::Scatter))
+        } else if term == atoms::bar() {
+            Ok(GraphTypeTerm(GraphType::Bar))
+        } else {
+            Err(nif_error!(
+                "graph type must be `:line`, `:scatter` or `:bar`"
+            ))
+        }
+    }
+}
+
+/// `nil` hides the legend.
+struct LegendPositionTerm(Option<LegendPosition>);
+
+impl Decoder<'_> for LegendPositionTerm {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let position: Option<rustler::Atom> = term.decode()?;
+
+        let Some(position) = position else {
+            return Ok(LegendPositionTerm(None));
+        };
+
+        let named = [
+            (atoms::top(), LegendPosition::Top),
+            (atoms::top_right(), LegendPosition::TopRight),
+            (atoms::top_left(), LegendPosition::TopLeft),
+            (atoms::left(), LegendPosition::Left),
+            (atoms::right(), LegendPosition::Right),
+            (atoms::bottom(), LegendPosition::Bottom),
+            (atoms::bottom_right(), LegendPosition::BottomRight),
+            (atoms::bottom_left(), LegendPosition::BottomLeft),
+        ];
+
+        named
+            .into_iter()
+            .find(|(name, _)| *name == position)
+            .map(|(_, position)| LegendPositionTerm(Some(position)))
+            .ok_or_else(|| nif_error!("unknown legend position"))
+    }
+}
+
+/// `%{name: name, data: [{x, y}], marker: marker, graph_type: type, style: style}`,
+/// where only `:data` is required.
+impl Decoder<'_> for DatasetSpec {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if !term.is_map() {
+            return Err(nif_error!("dataset must be a map"));
+        }
+
+        let data: Vec<(f64, f64)> = crate::options::get(term, atoms::data())?
+            .ok_or_else(|| nif_error!("dataset map must have `:data`"))?;
+
+        let mut dataset = Dataset::default();
+
+        if let Some(name) = crate::options::get::<String>(term, atoms::name())? {
+            dataset = dataset.name(name);
+        }
+
+        if let Some(marker) = crate::options::get::<Marker>(term, atoms::marker())? {
+            dataset = dataset.marker(marker.0);
+        }
+
+        if let Some(graph_type) = crate::options::get::<GraphTypeTerm>(term, atoms::graph_type())? {
+            dataset = dataset.graph_type(graph_type.0);
+        }
+
+        if let Some(style) = crate::options::get::<crate::style::Style>(term, atoms::style())? {
+            dataset = dataset.style(style.0);
+        }
+
+        Ok(DatasetSpec { dataset, data })
+    }
+}
+
+/// `%{title: title, bounds: {min, max}, labels: labels, style: style, labels_alignment: alignment}`,
+/// every key optional.
+struct AxisTerm(Axis<'static>);
+
+impl Decoder<'_> for AxisTerm {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if !term.is_map() {
+            return Err(nif_error!("axis must be a map"));
+        }
+
+        let mut axis = Axis::default();
+
+        if let Some(title) = crate::options::get::<String>(term, atoms::title())? {
+            axis = axis.title(title);
+        }
+
+        if let Some((min, max)) = crate::options::get::<(f64, f64)>(term, atoms::bounds())? {
+            axis = axis.bounds([min, max]);
+        }
+
+        if let Some(labels) = crate::options::get::<Vec<String>>(term, atoms::labels())? {
+            axis = axis.labels(labels);
+        }
+
+        if let Some(style) = crate::options::get::<crate::style::Style>(term, atoms::style())? {
+            axis = axis.style(style.0);
+        }
+
+        if let Some(alignment) =
+            crate::options::get::<crate::terminal::Alignment>(term, atoms::labels_alignment())?
+        {
+            axis = axis.labels_alignment(alignment.0);
+        }
+
+        Ok(AxisTerm(axis))
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn chart_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+    datasets: Vec<DatasetSpec>,
+) -> NifResult<ResourceArc<ChartResource>> {
+    let (block, block_theme) = block.take()?;
+
+    Ok(ResourceArc::new(ChartResource(
+        Mutex::new(ChartSpec {
+            block,
+            datasets,
+            x_axis: Axis::default(),
+            y_axis: Axis::default(),
+            legend_position: Some(LegendPosition::default()),
+        }),
+        block_theme,
+    )))
+}
+
+/// Replaces every dataset, for charts that are fed new points each frame.
+#[rustler::nif(schedule = "DirtyIo")]
+fn chart_datasets(
+    chart: ResourceArc<ChartResource>,
+    datasets: Vec<DatasetSpec>,
+) -> ResourceArc<ChartResource> {
+    chart.0.lock().unwrap().datasets = datasets;
+    chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn chart_x_axis(chart: ResourceArc<ChartResource>, axis: AxisTerm) -> ResourceArc<ChartResource> {
+    chart.0.lock().unwrap().x_axis = axis.0;
+    chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn chart_y_axis(chart: ResourceArc<ChartResource>, axis: AxisTerm) -> ResourceArc<ChartResource> {
+    chart.0.lock().unwrap().y_axis = axis.0;
+    chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn chart_legend_position(
+    chart: ResourceArc<ChartResource>,
+    position: LegendPositionTerm,
+) -> ResourceArc<ChartResource> {
+    chart.0.lock().unwrap().legend_position = position.0;
+    chart
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn chart_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    chart: ResourceArc<ChartResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    let spec = chart.lock();
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(&*spec, area);
+
+    Ok(())
+}
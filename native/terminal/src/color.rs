@@ -0,0 +1,370 @@
+use crate::terminal::nif_error;
+use rustler::types::tuple::get_tuple;
+use rustler::{Decoder, NifResult, Term};
+
+mod atoms {
+    rustler::atoms! {
+        rgb,
+        indexed,
+
+        reset,
+        black,
+        red,
+        green,
+        yellow,
+        blue,
+        magenta,
+        cyan,
+        gray,
+        dark_gray,
+        light_red,
+        light_green,
+        light_yellow,
+        light_blue,
+        light_magenta,
+        light_cyan,
+        white,
+    }
+}
+
+/// One of:
+///
+/// - a named color atom, e.g. `:red` or `:light_blue` (`:reset` uses the terminal default)
+/// - `{:indexed, n}` for the 256 color palette
+/// - `{:rgb, r, g, b}`
+/// - a `"#rrggbb"` hex string
+pub(crate) struct Color(pub ratatui::style::Color);
+
+impl Decoder<'_> for Color {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if term.is_binary() {
+            let hex: String = term.decode()?;
+            return parse_hex(&hex).map(Color);
+        }
+
+        if term.is_atom() {
+            return named(term).map(Color);
+        }
+
+        if term.is_tuple() {
+            let elements = get_tuple(term)?;
+
+            if let Some((tag, rest)) = elements.split_first() {
+                let tag: rustler::Atom = tag.decode()?;
+
+                match rest {
+                    [n] if tag == atoms::indexed() => {
+                        return Ok(Color(ratatui::style::Color::Indexed(component(
+                            *n,
+                            "indexed color",
+                        )?)))
+                    }
+                    [r, g, b] if tag == atoms::rgb() => {
+                        return Ok(Color(ratatui::style::Color::Rgb(
+                            component(*r, "red component")?,
+                            component(*g, "green component")?,
+                            component(*b, "blue component")?,
+                        )))
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        Err(nif_error!(format!(
+            "invalid color {term:?}, expected a color name atom, `{{:indexed, n}}`, `{{:rgb, r, g, b}}` or a `\"#rrggbb\"` string"
+        )))
+    }
+}
+
+fn named(term: Term) -> NifResult<ratatui::style::Color> {
+    let color: rustler::Atom = term.decode()?;
+
+    let named = [
+        (atoms::reset(), ratatui::style::Color::Reset),
+        (atoms::black(), ratatui::style::Color::Black),
+        (atoms::red(), ratatui::style::Color::Red),
+        (atoms::green(), ratatui::style::Color::Green),
+        (atoms::yellow(), ratatui::style::Color::Yellow),
+        (atoms::blue(), ratatui::style::Color::Blue),
+        (atoms::magenta(), ratatui::style::Color::Magenta),
+        (atoms::cyan(), ratatui::style::Color::Cyan),
+        (atoms::gray(), ratatui::style::Color::Gray),
+        (atoms::dark_gray(), ratatui::style::Color::DarkGray),
+        (atoms::light_red(), ratatui::style::Color::LightRed),
+        (atoms::light_green(), ratatui::style::Color::LightGreen),
+        (atoms::light_yellow(), ratatui::style::Color::LightYellow),
+        (atoms::light_blue(), ratatui::style::Color::LightBlue),
+        (atoms::light_magenta(), ratatui::style::Color::LightMagenta),
+        (atoms::light_cyan(), ratatui::style::Color::LightCyan),
+        (atoms::white(), ratatui::style::Color::White),
+    ];
+
+    named
+        .into_iter()
+        .find(|(name, _)| *name == color)
+        .map(|(_, color)| color)
+        .ok_or_else(|| nif_error!(format!("unknown color name {term:?}")))
+}
+
+fn component(term: Term, what: &str) -> NifResult<u8> {
+    let value: i64 = term
+        .decode()
+        .map_err(|_| nif_error!(format!("{what} must be an integer, got {term:?}")))?;
+
+    u8::try_from(value)
+        .map_err(|_| nif_error!(format!("{what} must be between 0 and 255, got {value}")))
+}
+
+fn parse_hex(hex: &str) -> NifResult<ratatui::style::Color> {
+    let invalid = || nif_error!(format!("invalid hex color {hex:?}, expected \"#rrggbb\""));
+
+    let digits = hex.strip_prefix('#').ok_or_else(invalid)?;
+
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|_| invalid());
+
+    Ok(ratatui::style::Color::Rgb(
+        channel(0)?,
+        channel(2)?,
+        channel(4)?,
+    ))
+}
+
+/// How many colors the terminal can show.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ColorSupport {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    None,
+}
+
+impl ColorSupport {
+    /// Follows the usual conventions: `NO_COLOR` disables color, `COLORTERM`
+    /// advertises truecolor, and `TERM` tells 256 color terminals and dumb
+    /// ones apart from the 16 color baseline.
+    pub(crate) fn detect() -> Self {
+        Self::from_env(
+            std::env::var("NO_COLOR").ok().as_deref(),
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    fn from_env(no_color: Option<&str>, colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if no_color.is_some_and(|v| !v.is_empty()) {
+            return ColorSupport::None;
+        }
+
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return ColorSupport::TrueColor;
+        }
+
+        let term = term.unwrap_or_default();
+        if term.is_empty() || term == "dumb" {
+            ColorSupport::None
+        } else if term.contains("truecolor") || term.contains("24bit") || term.contains("direct") {
+            ColorSupport::TrueColor
+        } else if term.contains("256color") {
+            ColorSupport::Ansi256
+        } else {
+            ColorSupport::Ansi16
+        }
+    }
+
+    /// Maps `color` to the nearest one the terminal can show.
+    pub(crate) fn downgrade(self, color: ratatui::style::Color) -> ratatui::style::Color {
+        use ratatui::style::Color::{Indexed, Reset, Rgb};
+
+        match (self, color) {
+            (ColorSupport::TrueColor, color) => color,
+            (ColorSupport::None, _) => Reset,
+            (ColorSupport::Ansi256, Rgb(r, g, b)) => Indexed(rgb_to_256(r, g, b)),
+            (ColorSupport::Ansi256, color) => color,
+            (ColorSupport::Ansi16, Rgb(r, g, b)) => rgb_to_16(r, g, b),
+            (ColorSupport::Ansi16, Indexed(n)) => {
+                let (r, g, b) = indexed_to_rgb(n);
+                rgb_to_16(r, g, b)
+            }
+            (ColorSupport::Ansi16, color) => color,
+        }
+    }
+}
+
+// xterm's default values for the 16 ANSI colors, in palette order
+const ANSI_16: [(ratatui::style::Color, (u8, u8, u8)); 16] = [
+    (ratatui::style::Color::Black, (0, 0, 0)),
+    (ratatui::style::Color::Red, (128, 0, 0)),
+    (ratatui::style::Color::Green, (0, 128, 0)),
+    (ratatui::style::Color::Yellow, (128, 128, 0)),
+    (ratatui::style::Color::Blue, (0, 0, 128)),
+    (ratatui::style::Color::Magenta, (128, 0, 128)),
+    (ratatui::style::Color::Cyan, (0, 128, 128)),
+    (ratatui::style::Color::Gray, (192, 192, 192)),
+    (ratatui::style::Color::DarkGray, (128, 128, 128)),
+    (ratatui::style::Color::LightRed, (255, 0, 0)),
+    (ratatui::style::Color::LightGreen, (0, 255, 0)),
+    (ratatui::style::Color::LightYellow, (255, 255, 0)),
+    (ratatui::style::Color::LightBlue, (0, 0, 255)),
+    (ratatui::style::Color::LightMagenta, (255, 0, 255)),
+    (ratatui::style::Color::LightCyan, (0, 255, 255)),
+    (ratatui::style::Color::White, (255, 255, 255)),
+];
+
+// channel levels of the 6x6x6 cube in palette entries 16-231
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).unsigned_abs();
+    d(r1, r2).pow(2) + d(g1, g2).pow(2) + d(b1, b2).pow(2)
+}
+
+fn indexed_to_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => ANSI_16[usize::from(n)].1,
+        16..=231 => {
+            let n = n - 16;
+            (
+                CUBE_LEVELS[usize::from(n / 36)],
+                CUBE_LEVELS[usize::from((n / 6) % 6)],
+                CUBE_LEVELS[usize::from(n % 6)],
+            )
+        }
+        232..=255 => {
+            let level = 8 + (n - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let nearest_level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(c))
+            .unwrap() as u8
+    };
+
+    let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+
+    let average = ((u16::from(r) + u16::from(g) + u16::from(b)) / 3) as u8;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23);
+
+    if distance((r, g, b), indexed_to_rgb(gray)) < distance((r, g, b), indexed_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn rgb_to_16(r: u8, g: u8, b: u8) -> ratatui::style::Color {
+    ANSI_16
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color::{self as C, Indexed, Rgb};
+
+    #[test]
+    fn no_color_wins_over_everything() {
+        assert_eq!(
+            ColorSupport::from_env(Some("1"), Some("truecolor"), Some("xterm-256color")),
+            ColorSupport::None
+        );
+    }
+
+    #[test]
+    fn empty_no_color_is_ignored() {
+        assert_eq!(
+            ColorSupport::from_env(Some(""), None, Some("xterm-256color")),
+            ColorSupport::Ansi256
+        );
+    }
+
+    #[test]
+    fn colorterm_wins_over_term() {
+        assert_eq!(
+            ColorSupport::from_env(None, Some("24bit"), Some("xterm")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, Some("truecolor"), Some("dumb")),
+            ColorSupport::TrueColor
+        );
+    }
+
+    #[test]
+    fn term_decides_without_colorterm() {
+        let from_term = |term| ColorSupport::from_env(None, None, term);
+
+        assert_eq!(from_term(Some("xterm-256color")), ColorSupport::Ansi256);
+        assert_eq!(from_term(Some("xterm-direct")), ColorSupport::TrueColor);
+        assert_eq!(from_term(Some("xterm")), ColorSupport::Ansi16);
+        assert_eq!(from_term(Some("dumb")), ColorSupport::None);
+        assert_eq!(from_term(None), ColorSupport::None);
+    }
+
+    #[test]
+    fn rgb_to_256_prefers_grayscale_ramp_for_grays() {
+        assert_eq!(
+            ColorSupport::Ansi256.downgrade(Rgb(18, 18, 18)),
+            Indexed(233)
+        );
+        assert_eq!(
+            ColorSupport::Ansi256.downgrade(Rgb(128, 128, 128)),
+            Indexed(244)
+        );
+    }
+
+    #[test]
+    fn rgb_to_256_uses_the_cube_for_colors() {
+        assert_eq!(ColorSupport::Ansi256.downgrade(Rgb(0, 0, 0)), Indexed(16));
+        assert_eq!(
+            ColorSupport::Ansi256.downgrade(Rgb(255, 0, 0)),
+            Indexed(196)
+        );
+        assert_eq!(
+            ColorSupport::Ansi256.downgrade(Rgb(95, 135, 175)),
+            Indexed(67)
+        );
+        assert_eq!(
+            ColorSupport::Ansi256.downgrade(Rgb(255, 255, 255)),
+            Indexed(231)
+        );
+    }
+
+    #[test]
+    fn rgb_and_indexed_to_16_pick_the_nearest_named_color() {
+        assert_eq!(
+            ColorSupport::Ansi16.downgrade(Rgb(128, 128, 128)),
+            C::DarkGray
+        );
+        assert_eq!(
+            ColorSupport::Ansi16.downgrade(Rgb(250, 10, 10)),
+            C::LightRed
+        );
+        assert_eq!(ColorSupport::Ansi16.downgrade(Indexed(196)), C::LightRed);
+        assert_eq!(ColorSupport::Ansi16.downgrade(Indexed(4)), C::Blue);
+        assert_eq!(ColorSupport::Ansi16.downgrade(Indexed(244)), C::DarkGray);
+    }
+
+    #[test]
+    fn named_colors_pass_through_and_none_resets() {
+        assert_eq!(ColorSupport::Ansi16.downgrade(C::Cyan), C::Cyan);
+        assert_eq!(ColorSupport::Ansi256.downgrade(Indexed(100)), Indexed(100));
+        assert_eq!(
+            ColorSupport::TrueColor.downgrade(Rgb(1, 2, 3)),
+            Rgb(1, 2, 3)
+        );
+        assert_eq!(ColorSupport::None.downgrade(C::Red), C::Reset);
+    }
+}
@@ -0,0 +1,292 @@
+use crate::terminal::nif_error;
+use ratatui::widgets::{Gauge, LineGauge};
+use rustler::{NifResult, ResourceArc};
+use std::sync::Mutex;
+
+pub(crate) struct GaugeResource(
+    pub Mutex<Option<Gauge<'static>>>,
+    pub crate::theme::Themed<Gauge<'static>>,
+);
+
+impl rustler::Resource for GaugeResource {}
+
+pub(crate) struct LineGaugeResource(
+    pub Mutex<Option<LineGauge<'static>>>,
+    pub crate::theme::Themed<LineGauge<'static>>,
+);
+
+impl rustler::Resource for LineGaugeResource {}
+
+// ratatui panics on an out of range ratio or percent, so check before handing them over
+fn check_ratio(ratio: f64) -> NifResult<()> {
+    if (0.0..=1.0).contains(&ratio) {
+        Ok(())
+    } else {
+        Err(nif_error!("ratio must be between 0.0 and 1.0"))
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn gauge_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+) -> NifResult<ResourceArc<GaugeResource>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, Gauge::block);
+
+    let gauge = Gauge::default().block(block);
+
+    Ok(ResourceArc::new(GaugeResource(
+        Mutex::new(Some(gauge)),
+        themed,
+    )))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn gauge_ratio(
+    gauge: ResourceArc<GaugeResource>,
+    ratio: f64,
+) -> NifResult<ResourceArc<GaugeResource>> {
+    check_ratio(ratio)?;
+
+    {
+        let mut lock = gauge.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in gauge_ratio");
+        *lock = Some(inner.ratio(ratio))
+    }
+
+    Ok(gauge)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn gauge_percent(
+    gauge: ResourceArc<GaugeResource>,
+    percent: u16,
+) -> NifResult<ResourceArc<GaugeResource>> {
+    if percent > 100 {
+        return Err(nif_error!("percent must be between 0 and 100"));
+    }
+
+    {
+        let mut lock = gauge.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in gauge_percent");
+        *lock = Some(inner.percent(percent))
+    }
+
+    Ok(gauge)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn gauge_label(gauge: ResourceArc<GaugeResource>, label: String) -> ResourceArc<GaugeResource> {
+    {
+        let mut lock = gauge.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in gauge_label");
+        *lock = Some(inner.label(label))
+    }
+
+    gauge
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn gauge_style(
+    gauge: ResourceArc<GaugeResource>,
+    style: crate::style::Style,
+) -> ResourceArc<GaugeResource> {
+    {
+        let mut lock = gauge.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in gauge_style");
+        *lock = Some(inner.style(style.0))
+    }
+
+    gauge.1.set("style", &style, Gauge::style);
+
+    gauge
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn gauge_gauge_style(
+    gauge: ResourceArc<GaugeResource>,
+    style: crate::style::Style,
+) -> ResourceArc<GaugeResource> {
+    {
+        let mut lock = gauge.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in gauge_gauge_style");
+        *lock = Some(inner.gauge_style(style.0))
+    }
+
+    gauge.1.set("gauge_style", &style, Gauge::gauge_style);
+
+    gauge
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn gauge_use_unicode(
+    gauge: ResourceArc<GaugeResource>,
+    use_unicode: bool,
+) -> ResourceArc<GaugeResource> {
+    {
+        let mut lock = gauge.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in gauge_use_unicode");
+        *lock = Some(inner.use_unicode(use_unicode))
+    }
+
+    gauge
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn gauge_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    gauge: ResourceArc<GaugeResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    gauge.1.restyle(&gauge.0);
+    let gauge = gauge
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| nif_error!("gauge has already been used"))?;
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(gauge, area);
+
+    Ok(())
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn line_gauge_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+) -> NifResult<ResourceArc<LineGaugeResource>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, LineGauge::block);
+
+    let line_gauge = LineGauge::default().block(block);
+
+    Ok(ResourceArc::new(LineGaugeResource(
+        Mutex::new(Some(line_gauge)),
+        themed,
+    )))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn line_gauge_ratio(
+    line_gauge: ResourceArc<LineGaugeResource>,
+    ratio: f64,
+) -> NifResult<ResourceArc<LineGaugeResource>> {
+    check_ratio(ratio)?;
+
+    {
+        let mut lock = line_gauge.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in line_gauge_ratio");
+        *lock = Some(inner.ratio(ratio))
+    }
+
+    Ok(line_gauge)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn line_gauge_label(
+    line_gauge: ResourceArc<LineGaugeResource>,
+    label: String,
+) -> ResourceArc<LineGaugeResource> {
+    {
+        let mut lock = line_gauge.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in line_gauge_label");
+        *lock = Some(inner.label(label))
+    }
+
+    line_gauge
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn line_gauge_style(
+    line_gauge: ResourceArc<LineGaugeResource>,
+    style: crate::style::Style,
+) -> ResourceArc<LineGaugeResource> {
+    {
+        let mut lock = line_gauge.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in line_gauge_style");
+        *lock = Some(inner.style(style.0))
+    }
+
+    line_gauge.1.set("style", &style, LineGauge::style);
+
+    line_gauge
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn line_gauge_filled_style(
+    line_gauge: ResourceArc<LineGaugeResource>,
+    style: crate::style::Style,
+) -> ResourceArc<LineGaugeResource> {
+    {
+        let mut lock = line_gauge.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in line_gauge_filled_style");
+        *lock = Some(inner.filled_style(style.0))
+    }
+
+    line_gauge
+        .1
+        .set("filled_style", &style, LineGauge::filled_style);
+
+    line_gauge
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn line_gauge_unfilled_style(
+    line_gauge: ResourceArc<LineGaugeResource>,
+    style: crate::style::Style,
+) -> ResourceArc<LineGaugeResource> {
+    {
+        let mut lock = line_gauge.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in line_gauge_unfilled_style");
+        *lock = Some(inner.unfilled_style(style.0))
+    }
+
+    line_gauge
+        .1
+        .set("unfilled_style", &style, LineGauge::unfilled_style);
+
+    line_gauge
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn line_gauge_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    line_gauge: ResourceArc<LineGaugeResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    line_gauge.1.restyle(&line_gauge.0);
+    let line_gauge = line_gauge
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| nif_error!("line gauge has already been used"))?;
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(line_gauge, area);
+
+    Ok(())
+}
@@ -0,0 +1,730 @@
+//! Syntax highlighting for the code view. Each language gets a small
+//! hand-written scanner that recognizes just enough (comments, strings,
+//! numbers, keywords, ...) to color source the way an editor roughly would;
+//! it is not a parser and never rejects input.
+//!
+//! Token styles can be overridden by theme styles named `:code_keyword`,
+//! `:code_string`, `:code_number`, `:code_comment`, `:code_atom`,
+//! `:code_type`, `:code_function`, `:code_added`, `:code_removed`,
+//! `:code_hunk`, `:code_header` and `:code_line_number`.
+
+use crate::terminal::nif_error;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::Paragraph,
+};
+use rustler::types::tuple::get_tuple;
+use rustler::{Decoder, NifResult, ResourceArc, Term};
+
+mod atoms {
+    rustler::atoms! {
+        elixir,
+        rust,
+        json,
+        diff,
+
+        line_numbers,
+
+        code,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Language {
+    Elixir,
+    Rust,
+    Json,
+    Diff,
+}
+
+impl Decoder<'_> for Language {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::elixir() {
+            Ok(Language::Elixir)
+        } else if term == atoms::rust() {
+            Ok(Language::Rust)
+        } else if term == atoms::json() {
+            Ok(Language::Json)
+        } else if term == atoms::diff() {
+            Ok(Language::Diff)
+        } else {
+            Err(nif_error!(
+                "language must be `:elixir`, `:rust`, `:json` or `:diff`"
+            ))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token {
+    Plain,
+    Keyword,
+    String,
+    Number,
+    Comment,
+    Atom,
+    Type,
+    Function,
+    Added,
+    Removed,
+    Hunk,
+    Header,
+}
+
+impl Token {
+    fn style(self) -> Style {
+        let (name, default) = match self {
+            Token::Plain => return Style::default(),
+            Token::Keyword => ("code_keyword", Style::new().fg(Color::Magenta)),
+            Token::String => ("code_string", Style::new().fg(Color::Green)),
+            Token::Number => ("code_number", Style::new().fg(Color::Yellow)),
+            Token::Comment => (
+                "code_comment",
+                Style::new()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+            Token::Atom => ("code_atom", Style::new().fg(Color::Cyan)),
+            Token::Type => ("code_type", Style::new().fg(Color::LightYellow)),
+            Token::Function => ("code_function", Style::new().fg(Color::Blue)),
+            Token::Added => ("code_added", Style::new().fg(Color::Green)),
+            Token::Removed => ("code_removed", Style::new().fg(Color::Red)),
+            Token::Hunk => ("code_hunk", Style::new().fg(Color::Cyan)),
+            Token::Header => ("code_header", Style::new().add_modifier(Modifier::BOLD)),
+        };
+
+        crate::theme::lookup(name).unwrap_or(default)
+    }
+}
+
+const ELIXIR_KEYWORDS: &[&str] = &[
+    "after",
+    "alias",
+    "and",
+    "case",
+    "catch",
+    "cond",
+    "def",
+    "defdelegate",
+    "defexception",
+    "defguard",
+    "defimpl",
+    "defmacro",
+    "defmacrop",
+    "defmodule",
+    "defp",
+    "defprotocol",
+    "defstruct",
+    "do",
+    "else",
+    "end",
+    "fn",
+    "for",
+    "if",
+    "import",
+    "in",
+    "not",
+    "or",
+    "quote",
+    "raise",
+    "receive",
+    "require",
+    "rescue",
+    "try",
+    "unless",
+    "unquote",
+    "use",
+    "when",
+    "with",
+];
+
+const ELIXIR_CONSTANTS: &[&str] = &["true", "false", "nil"];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+const JSON_CONSTANTS: &[&str] = &["true", "false", "null"];
+
+/// Splits `source` into styled tokens that, concatenated, give back `source`.
+struct Scanner<'a> {
+    source: &'a str,
+    position: usize,
+    tokens: Vec<(Token, &'a str)>,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(source: &'a str) -> Self {
+        Scanner {
+            source,
+            position: 0,
+            tokens: vec![],
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.rest().chars().nth(1)
+    }
+
+    fn emit(&mut self, token: Token, len: usize) {
+        let text = &self.source[self.position..self.position + len];
+
+        match self.tokens.last_mut() {
+            // merge runs of plain text so lines don't end up with a span per character
+            Some((Token::Plain, last)) if token == Token::Plain => {
+                let start = last.as_ptr() as usize - self.source.as_ptr() as usize;
+                *last = &self.source[start..self.position + len];
+            }
+            _ => self.tokens.push((token, text)),
+        }
+
+        self.position += len;
+    }
+
+    /// Length of the prefix of the rest of the input whose chars satisfy `f`.
+    fn span_while(&self, f: impl Fn(char) -> bool) -> usize {
+        self.rest()
+            .char_indices()
+            .find(|(_, c)| !f(*c))
+            .map_or(self.rest().len(), |(i, _)| i)
+    }
+
+    fn line_comment(&mut self) {
+        let len = self.rest().find('\n').unwrap_or(self.rest().len());
+        self.emit(Token::Comment, len);
+    }
+
+    /// A string starting at the current position and ending at the next
+    /// unescaped `close`, or at the end of the input if it never closes.
+    fn quoted(&mut self, token: Token, open_len: usize, close: &str) {
+        let rest = self.rest();
+        let mut chars = rest.char_indices().skip_while(|(i, _)| *i < open_len);
+        let mut len = rest.len();
+
+        while let Some((i, c)) = chars.next() {
+            if c == '\\' {
+                chars.next();
+            } else if rest[i..].starts_with(close) {
+                len = i + close.len();
+                break;
+            }
+        }
+
+        self.emit(token, len);
+    }
+
+    fn number(&mut self) {
+        let len = self.span_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+
+        // don't swallow a range like `1..10` or a trailing method call
+        let len = self.rest()[..len]
+            .match_indices('.')
+            .find(|(i, _)| {
+                !self.rest()[i + 1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_digit())
+            })
+            .map_or(len, |(i, _)| i);
+
+        self.emit(Token::Number, len);
+    }
+
+    fn identifier_len(&self, extra: &[char]) -> usize {
+        self.span_while(|c| c.is_alphanumeric() || c == '_' || extra.contains(&c))
+    }
+
+    fn other(&mut self) {
+        let len = self.peek().map_or(0, char::len_utf8);
+        self.emit(Token::Plain, len);
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn scan_elixir(source: &str) -> Vec<(Token, &str)> {
+    let mut s = Scanner::new(source);
+
+    while let Some(c) = s.peek() {
+        match c {
+            '#' => s.line_comment(),
+            '"' if s.rest().starts_with("\"\"\"") => s.quoted(Token::String, 3, "\"\"\""),
+            '"' => s.quoted(Token::String, 1, "\""),
+            '\'' => s.quoted(Token::String, 1, "'"),
+            '?' if s.peek_second().is_some_and(|c| !c.is_whitespace()) => {
+                // character literals, `?a` or `?\n`
+                let len = s
+                    .rest()
+                    .chars()
+                    .skip(1)
+                    .take(if s.peek_second() == Some('\\') { 2 } else { 1 })
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+                s.emit(Token::Number, 1 + len);
+            }
+            ':' if s.peek_second() == Some('"') => s.quoted(Token::Atom, 2, "\""),
+            ':' if s.peek_second().is_some_and(is_identifier_start) => {
+                s.position += 1;
+                let len = s.identifier_len(&['?', '!']);
+                s.position -= 1;
+                s.emit(Token::Atom, len + 1);
+            }
+            '@' if s.peek_second().is_some_and(is_identifier_start) => {
+                s.position += 1;
+                let len = s.identifier_len(&[]);
+                s.position -= 1;
+                s.emit(Token::Atom, len + 1);
+            }
+            c if c.is_ascii_digit() => s.number(),
+            c if c.is_uppercase() => {
+                let len = s.identifier_len(&[]);
+                s.emit(Token::Type, len);
+            }
+            c if is_identifier_start(c) => {
+                let len = s.identifier_len(&['?', '!']);
+                let word = &s.rest()[..len];
+                let next = s.rest()[len..].chars().next();
+
+                let token = if next == Some(':')
+                    && s.rest()[len..]
+                        .chars()
+                        .nth(1)
+                        .is_some_and(char::is_whitespace)
+                {
+                    // keyword list key, `do: ...`
+                    s.emit(Token::Atom, len + 1);
+                    continue;
+                } else if ELIXIR_KEYWORDS.contains(&word) {
+                    Token::Keyword
+                } else if ELIXIR_CONSTANTS.contains(&word) {
+                    Token::Atom
+                } else if next == Some('(') {
+                    Token::Function
+                } else {
+                    Token::Plain
+                };
+
+                s.emit(token, len);
+            }
+            _ => s.other(),
+        }
+    }
+
+    s.tokens
+}
+
+fn scan_rust(source: &str) -> Vec<(Token, &str)> {
+    let mut s = Scanner::new(source);
+
+    while let Some(c) = s.peek() {
+        match c {
+            '/' if s.peek_second() == Some('/') => s.line_comment(),
+            '/' if s.peek_second() == Some('*') => {
+                let len = s.rest()[2..].find("*/").map_or(s.rest().len(), |i| i + 4);
+                s.emit(Token::Comment, len);
+            }
+            '"' => s.quoted(Token::String, 1, "\""),
+            'b' if s.peek_second() == Some('"') => s.quoted(Token::String, 2, "\""),
+            'r' if s.rest().starts_with("r\"") || s.rest().starts_with("r#\"") => {
+                let hashes = s.rest()[1..].chars().take_while(|&c| c == '#').count();
+                let close = format!("\"{}", "#".repeat(hashes));
+                let open_len = 2 + hashes;
+                let len = s.rest()[open_len..]
+                    .find(&close)
+                    .map_or(s.rest().len(), |i| open_len + i + close.len());
+                s.emit(Token::String, len);
+            }
+            '\'' => {
+                let rest = s.rest();
+                let mut chars = rest.char_indices().skip(1);
+
+                // a char literal closes within a few chars; otherwise it's a lifetime
+                let char_len = match chars.next() {
+                    Some((_, '\\')) => rest.get(3..).and_then(|r| r.find('\'')).map(|i| i + 4),
+                    Some((i, c)) if rest[i + c.len_utf8()..].starts_with('\'') => {
+                        Some(i + c.len_utf8() + 1)
+                    }
+                    _ => None,
+                };
+
+                if let Some(len) = char_len {
+                    s.emit(Token::String, len);
+                } else {
+                    s.position += 1;
+                    let len = s.identifier_len(&[]);
+                    s.position -= 1;
+                    s.emit(Token::Atom, len + 1);
+                }
+            }
+            c if c.is_ascii_digit() => s.number(),
+            c if is_identifier_start(c) => {
+                let len = s.identifier_len(&[]);
+                let word = &s.rest()[..len];
+                let next = s.rest()[len..].chars().next();
+
+                let token = if RUST_KEYWORDS.contains(&word) {
+                    Token::Keyword
+                } else if next == Some('!') {
+                    s.emit(Token::Function, len + 1);
+                    continue;
+                } else if word.starts_with(char::is_uppercase) {
+                    Token::Type
+                } else if next == Some('(') {
+                    Token::Function
+                } else {
+                    Token::Plain
+                };
+
+                s.emit(token, len);
+            }
+            _ => s.other(),
+        }
+    }
+
+    s.tokens
+}
+
+fn scan_json(source: &str) -> Vec<(Token, &str)> {
+    let mut s = Scanner::new(source);
+
+    while let Some(c) = s.peek() {
+        match c {
+            '"' => {
+                s.quoted(Token::String, 1, "\"");
+
+                // object keys are styled apart from string values
+                if s.rest().trim_start().starts_with(':') {
+                    s.tokens.last_mut().unwrap().0 = Token::Atom;
+                }
+            }
+            '-' if s.peek_second().is_some_and(|c| c.is_ascii_digit()) => {
+                let len = 1 + s.rest()[1..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || "+-.".contains(c)))
+                    .unwrap_or(s.rest().len() - 1);
+                s.emit(Token::Number, len);
+            }
+            c if c.is_ascii_digit() => {
+                let len = s.span_while(|c| c.is_ascii_alphanumeric() || "+-.".contains(c));
+                s.emit(Token::Number, len);
+            }
+            c if c.is_alphabetic() => {
+                let len = s.identifier_len(&[]);
+                let token = if JSON_CONSTANTS.contains(&&s.rest()[..len]) {
+                    Token::Keyword
+                } else {
+                    Token::Plain
+                };
+                s.emit(token, len);
+            }
+            _ => s.other(),
+        }
+    }
+
+    s.tokens
+}
+
+fn scan_diff(source: &str) -> Vec<(Token, &str)> {
+    source
+        .split_inclusive('\n')
+        .map(|line| {
+            let token = if line.starts_with("+++")
+                || line.starts_with("---")
+                || line.starts_with("diff ")
+                || line.starts_with("index ")
+            {
+                Token::Header
+            } else if line.starts_with("@@") {
+                Token::Hunk
+            } else if line.starts_with('+') {
+                Token::Added
+            } else if line.starts_with('-') {
+                Token::Removed
+            } else {
+                Token::Plain
+            };
+
+            (token, line)
+        })
+        .collect()
+}
+
+/// Highlights `source`, optionally prefixing each line with its number.
+fn highlight(source: &str, language: Language, line_numbers: bool) -> Text<'static> {
+    let tokens = match language {
+        Language::Elixir => scan_elixir(source),
+        Language::Rust => scan_rust(source),
+        Language::Json => scan_json(source),
+        Language::Diff => scan_diff(source),
+    };
+
+    let mut lines = vec![vec![]];
+
+    for (token, text) in tokens {
+        let style = token.style();
+
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(vec![]);
+            }
+
+            if !part.is_empty() {
+                lines
+                    .last_mut()
+                    .unwrap()
+                    .push(Span::styled(part.to_string(), style));
+            }
+        }
+    }
+
+    // a trailing newline ends the last line rather than starting a new one
+    if source.ends_with('\n') {
+        lines.pop();
+    }
+
+    let gutter_width = lines.len().to_string().len();
+    let gutter_style = crate::theme::lookup("code_line_number")
+        .unwrap_or_else(|| Style::new().fg(Color::DarkGray));
+
+    let lines = lines.into_iter().enumerate().map(|(i, mut spans)| {
+        if line_numbers {
+            spans.insert(
+                0,
+                Span::styled(format!("{:>gutter_width$} ", i + 1), gutter_style),
+            );
+        }
+        Line::from(spans)
+    });
+
+    Text::from_iter(lines)
+}
+
+/// Options for `code_render/6` and `{:code, ...}` text, a map or keyword list.
+struct CodeOptions {
+    line_numbers: bool,
+}
+
+impl Decoder<'_> for CodeOptions {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if !crate::options::is_options(term) {
+            return Err(nif_error!("code options must be a map or keyword list"));
+        }
+
+        let line_numbers =
+            crate::options::get::<bool>(term, atoms::line_numbers())?.unwrap_or(false);
+
+        Ok(CodeOptions { line_numbers })
+    }
+}
+
+/// Parses `{:code, language, source}` or `{:code, language, source, options}`
+/// into highlighted text, or returns `None` for any other term. This lets
+/// code go anywhere text does, e.g. a scrollable `paragraph_new`.
+pub(crate) fn code(term: Term) -> NifResult<Option<Text<'static>>> {
+    let Ok(elements) = get_tuple(term) else {
+        return Ok(None);
+    };
+
+    match elements.as_slice() {
+        [tag, language, source, rest @ ..]
+            if rest.len() <= 1 && tag.decode::<rustler::Atom>().ok() == Some(atoms::code()) =>
+        {
+            let options = match rest {
+                [options] => options.decode()?,
+                _ => CodeOptions {
+                    line_numbers: false,
+                },
+            };
+
+            Ok(Some(highlight(
+                source.decode()?,
+                language.decode()?,
+                options.line_numbers,
+            )))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Draws highlighted code with no block, scrolling or wrapping; for those,
+/// build a paragraph from `{:code, language, source, options}` text instead.
+#[rustler::nif(schedule = "DirtyIo")]
+fn code_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    source: &str,
+    language: Language,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+    options: CodeOptions,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    let paragraph = Paragraph::new(highlight(source, language, options.line_numbers));
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(paragraph, area);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scans `source`, checks that the tokens add back up to it, and returns
+    /// the ones that get highlighted.
+    fn highlighted<'a>(
+        scan: fn(&'a str) -> Vec<(Token, &'a str)>,
+        source: &'a str,
+    ) -> Vec<(Token, &'a str)> {
+        let tokens = scan(source);
+
+        assert_eq!(
+            tokens.iter().map(|(_, text)| *text).collect::<String>(),
+            source
+        );
+
+        tokens
+            .into_iter()
+            .filter(|(token, _)| *token != Token::Plain)
+            .collect()
+    }
+
+    fn assert_has(tokens: &[(Token, &str)], expected: &[(Token, &str)]) {
+        for token in expected {
+            assert!(tokens.contains(token), "{token:?} not in {tokens:?}");
+        }
+    }
+
+    #[test]
+    fn elixir() {
+        let source = r#"defmodule Foo do
+  @moduledoc """
+  Docs
+  """
+  def valid?(x), do: x in 1..10 # comment
+  def run, do: {:ok, ?a, ?\n, 3.14, [label: :"x y"]}
+end
+"#;
+
+        let tokens = highlighted(scan_elixir, source);
+
+        assert_has(
+            &tokens,
+            &[
+                (Token::Keyword, "defmodule"),
+                (Token::Type, "Foo"),
+                (Token::Atom, "@moduledoc"),
+                (Token::String, "\"\"\"\n  Docs\n  \"\"\""),
+                (Token::Function, "valid?"),
+                (Token::Number, "1"),
+                (Token::Number, "10"),
+                (Token::Comment, "# comment"),
+                (Token::Atom, ":ok"),
+                (Token::Number, "?a"),
+                (Token::Number, "?\\n"),
+                (Token::Number, "3.14"),
+                (Token::Atom, "label:"),
+                (Token::Atom, ":\"x y\""),
+                (Token::Keyword, "end"),
+            ],
+        );
+    }
+
+    #[test]
+    fn rust() {
+        let source = r##"fn first<'a>(v: &'a Vec<char>) -> char {
+    /* block */ println!("{}", r#"raw"#); // line
+    if v.is_empty() { '\'' } else { 'x' }
+}
+"##;
+
+        let tokens = highlighted(scan_rust, source);
+
+        assert_has(
+            &tokens,
+            &[
+                (Token::Keyword, "fn"),
+                (Token::Atom, "'a"),
+                (Token::Type, "Vec"),
+                (Token::Comment, "/* block */"),
+                (Token::Function, "println!"),
+                (Token::String, "\"{}\""),
+                (Token::String, "r#\"raw\"#"),
+                (Token::Comment, "// line"),
+                (Token::String, "'\\''"),
+                (Token::String, "'x'"),
+            ],
+        );
+    }
+
+    #[test]
+    fn json() {
+        let source = r#"{"a": [1, -2.5e3, true, null], "b": "c"}"#;
+
+        let tokens = highlighted(scan_json, source);
+
+        assert_eq!(
+            tokens,
+            [
+                (Token::Atom, "\"a\""),
+                (Token::Number, "1"),
+                (Token::Number, "-2.5e3"),
+                (Token::Keyword, "true"),
+                (Token::Keyword, "null"),
+                (Token::Atom, "\"b\""),
+                (Token::String, "\"c\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let source = "--- a/x\n+++ b/x\n@@ -1 +1 @@\n-old\n+new\n same\n";
+
+        let tokens = highlighted(scan_diff, source);
+
+        assert_has(
+            &tokens,
+            &[
+                (Token::Header, "--- a/x\n"),
+                (Token::Header, "+++ b/x\n"),
+                (Token::Hunk, "@@ -1 +1 @@\n"),
+                (Token::Removed, "-old\n"),
+                (Token::Added, "+new\n"),
+            ],
+        );
+    }
+
+    #[test]
+    fn trailing_newline_does_not_add_a_line() {
+        assert_eq!(highlight("a\nb\n", Language::Diff, false).lines.len(), 2);
+        assert_eq!(highlight("a\nb", Language::Diff, false).lines.len(), 2);
+    }
+
+    #[test]
+    fn line_numbers_are_right_aligned() {
+        let source = "1\n".repeat(10);
+        let text = highlight(&source, Language::Json, true);
+
+        assert_eq!(text.lines[0].spans[0].content, " 1 ");
+        assert_eq!(text.lines[9].spans[0].content, "10 ");
+        assert_eq!(text.lines[9].spans[1].content, "1");
+    }
+}
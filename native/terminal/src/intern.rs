@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+/// Some ratatui builders (highlight symbols, symbol sets) only take `&'a str`,
+/// and our widget resources are `'static`. Strings coming from Elixir are
+/// leaked once and reused, so the memory cost is bounded by the number of
+/// distinct symbols an application uses.
+pub(crate) fn intern(s: &str) -> &'static str {
+    static INTERNED: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+    let mut interned = INTERNED
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap();
+
+    if let Some(existing) = interned.get(s) {
+        existing
+    } else {
+        let leaked: &'static str = Box::leak(s.to_owned().into_boxed_str());
+        interned.insert(leaked);
+        leaked
+    }
+}
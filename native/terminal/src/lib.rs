@@ -1,14 +1,53 @@
 use rustler::{Env, Term};
 use terminal::TerminalResource;
 
+pub(crate) mod bar_chart;
 pub(crate) mod block;
+pub(crate) mod calendar;
+pub(crate) mod canvas;
+pub(crate) mod chart;
+pub(crate) mod color;
+pub(crate) mod gauge;
+pub(crate) mod highlight;
+pub(crate) mod intern;
+pub(crate) mod list;
+pub(crate) mod markup;
+pub(crate) mod options;
 pub(crate) mod paragraph;
+pub(crate) mod render;
+pub(crate) mod scrollbar;
+pub(crate) mod sparkline;
+pub(crate) mod style;
+pub(crate) mod table;
+pub(crate) mod tabs;
 pub(crate) mod terminal;
+pub(crate) mod text;
+pub(crate) mod theme;
+pub(crate) mod unicode;
 
 fn load(env: Env, _term: Term) -> bool {
     env.register::<crate::block::BlockResource>().unwrap();
     env.register::<crate::paragraph::ParagraphResource>()
         .unwrap();
+    env.register::<crate::list::ListResource>().unwrap();
+    env.register::<crate::list::ListStateResource>().unwrap();
+    env.register::<crate::table::TableResource>().unwrap();
+    env.register::<crate::table::TableStateResource>().unwrap();
+    env.register::<crate::tabs::TabsResource>().unwrap();
+    env.register::<crate::gauge::GaugeResource>().unwrap();
+    env.register::<crate::gauge::LineGaugeResource>().unwrap();
+    env.register::<crate::sparkline::SparklineResource>()
+        .unwrap();
+    env.register::<crate::bar_chart::BarChartResource>()
+        .unwrap();
+    env.register::<crate::chart::ChartResource>().unwrap();
+    env.register::<crate::canvas::CanvasResource>().unwrap();
+    env.register::<crate::scrollbar::ScrollbarResource>()
+        .unwrap();
+    env.register::<crate::scrollbar::ScrollbarStateResource>()
+        .unwrap();
+    env.register::<crate::calendar::CalendarResource>().unwrap();
+    env.register::<crate::theme::ThemeResource>().unwrap();
     env.register::<TerminalResource>().unwrap();
     env.register::<terminal::ChunksResource>().unwrap();
     true
@@ -0,0 +1,143 @@
+use crate::terminal::nif_error;
+use ratatui::widgets::{List, ListState};
+use rustler::{NifResult, ResourceArc};
+use std::sync::Mutex;
+
+pub(crate) struct ListResource(
+    pub Mutex<Option<List<'static>>>,
+    pub crate::theme::Themed<List<'static>>,
+);
+
+impl rustler::Resource for ListResource {}
+
+/// Selection and scroll offset for a list. Lives across frames so the
+/// Elixir side doesn't have to track either.
+pub(crate) struct ListStateResource(pub Mutex<ListState>);
+
+impl rustler::Resource for ListStateResource {}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+    items: Vec<crate::text::Text>,
+) -> NifResult<ResourceArc<ListResource>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, List::block);
+
+    let list = List::new(items.into_iter().map(|item| item.0)).block(block);
+
+    Ok(ResourceArc::new(ListResource(
+        Mutex::new(Some(list)),
+        themed,
+    )))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_highlight_symbol(
+    list: ResourceArc<ListResource>,
+    symbol: &str,
+) -> ResourceArc<ListResource> {
+    {
+        let mut lock = list.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in list_highlight_symbol");
+        *lock = Some(inner.highlight_symbol(crate::intern::intern(symbol)))
+    }
+
+    list
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_highlight_style(
+    list: ResourceArc<ListResource>,
+    style: crate::style::Style,
+) -> ResourceArc<ListResource> {
+    {
+        let mut lock = list.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in list_highlight_style");
+        *lock = Some(inner.highlight_style(style.0))
+    }
+
+    list.1.set("highlight_style", &style, List::highlight_style);
+
+    list
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    list: ResourceArc<ListResource>,
+    state: ResourceArc<ListStateResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    list.1.restyle(&list.0);
+    let list = list.0.lock().unwrap();
+    let list = list
+        .as_ref()
+        .ok_or_else(|| nif_error!("list has already been used"))?;
+    let mut state = state.0.lock().unwrap();
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_stateful_widget(list, area, &mut state);
+
+    Ok(())
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_state_new() -> ResourceArc<ListStateResource> {
+    ResourceArc::new(ListStateResource(Mutex::new(ListState::default())))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_state_select(
+    state: ResourceArc<ListStateResource>,
+    index: Option<usize>,
+) -> ResourceArc<ListStateResource> {
+    state.0.lock().unwrap().select(index);
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_state_select_next(state: ResourceArc<ListStateResource>) -> ResourceArc<ListStateResource> {
+    state.0.lock().unwrap().select_next();
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_state_select_previous(
+    state: ResourceArc<ListStateResource>,
+) -> ResourceArc<ListStateResource> {
+    state.0.lock().unwrap().select_previous();
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_state_select_first(
+    state: ResourceArc<ListStateResource>,
+) -> ResourceArc<ListStateResource> {
+    state.0.lock().unwrap().select_first();
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_state_select_last(state: ResourceArc<ListStateResource>) -> ResourceArc<ListStateResource> {
+    state.0.lock().unwrap().select_last();
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_state_selected(state: ResourceArc<ListStateResource>) -> Option<usize> {
+    state.0.lock().unwrap().selected()
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn list_state_offset(state: ResourceArc<ListStateResource>) -> usize {
+    state.0.lock().unwrap().offset()
+}
@@ -0,0 +1,286 @@
+//! A small inline markup language for styled text:
+//!
+//! ```text
+//! [bold red]Error:[/] disk [u]full[/]
+//! ```
+//!
+//! `[words]` opens a tag and `[/]` closes the most recently opened one
+//! (`[/words]` also works, but must match the tag it closes). Tags nest, and
+//! any still open at the end of the input are closed implicitly. Each word in
+//! a tag is a modifier (`bold`, `italic`, `u`, ...), a color understood by
+//! ratatui (`red`, `light_blue`, `#ff8800`, `208`), or `on <color>` for the
+//! background. `\[` and `\\` produce a literal `[` and `\`.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub(crate) struct MarkupError {
+    pub offset: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "markup error at byte {}: {}", self.offset, self.message)
+    }
+}
+
+fn modifier(word: &str) -> Option<Modifier> {
+    match word {
+        "bold" | "b" => Some(Modifier::BOLD),
+        "dim" => Some(Modifier::DIM),
+        "italic" | "i" => Some(Modifier::ITALIC),
+        "underlined" | "underline" | "u" => Some(Modifier::UNDERLINED),
+        "slow_blink" | "blink" => Some(Modifier::SLOW_BLINK),
+        "rapid_blink" => Some(Modifier::RAPID_BLINK),
+        "reversed" | "reverse" => Some(Modifier::REVERSED),
+        "hidden" => Some(Modifier::HIDDEN),
+        "crossed_out" | "strike" | "s" => Some(Modifier::CROSSED_OUT),
+        _ => None,
+    }
+}
+
+/// Parses the inside of an opening tag; `offset` is where it starts in the input.
+fn parse_tag(tag: &str, offset: usize) -> Result<Style, MarkupError> {
+    let mut style = Style::default();
+    let mut words = tag.split(' ').filter(|w| !w.is_empty()).peekable();
+
+    if words.peek().is_none() {
+        return Err(MarkupError {
+            offset,
+            message: "empty tag".to_string(),
+        });
+    }
+
+    while let Some(word) = words.next() {
+        let word_offset = offset + (word.as_ptr() as usize - tag.as_ptr() as usize);
+
+        if word == "on" {
+            let Some(color) = words.next() else {
+                return Err(MarkupError {
+                    offset: word_offset,
+                    message: "`on` must be followed by a color".to_string(),
+                });
+            };
+
+            let color_offset = offset + (color.as_ptr() as usize - tag.as_ptr() as usize);
+            style = style.bg(Color::from_str(color).map_err(|_| MarkupError {
+                offset: color_offset,
+                message: format!("unknown color `{color}`"),
+            })?);
+        } else if let Some(modifier) = modifier(word) {
+            style = style.add_modifier(modifier);
+        } else if let Ok(color) = Color::from_str(word) {
+            style = style.fg(color);
+        } else {
+            return Err(MarkupError {
+                offset: word_offset,
+                message: format!("unknown style `{word}`"),
+            });
+        }
+    }
+
+    Ok(style)
+}
+
+struct Builder {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    buffer: String,
+    // open tags with their source text, for matching `[/tag]`
+    stack: Vec<(String, Style)>,
+}
+
+impl Builder {
+    fn style(&self) -> Style {
+        self.stack
+            .iter()
+            .fold(Style::default(), |acc, (_, style)| acc.patch(*style))
+    }
+
+    fn flush_span(&mut self) {
+        if !self.buffer.is_empty() {
+            let content = std::mem::take(&mut self.buffer);
+            self.spans.push(Span::styled(content, self.style()));
+        }
+    }
+
+    fn flush_line(&mut self) {
+        self.flush_span();
+        self.lines.push(Line::from(std::mem::take(&mut self.spans)));
+    }
+}
+
+pub(crate) fn parse(input: &str) -> Result<Text<'static>, MarkupError> {
+    let mut builder = Builder {
+        lines: vec![],
+        spans: vec![],
+        buffer: String::new(),
+        stack: vec![],
+    };
+
+    let mut chars = input.char_indices();
+
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, escaped @ ('[' | '\\'))) => builder.buffer.push(escaped),
+                Some((_, other)) => {
+                    builder.buffer.push('\\');
+                    builder.buffer.push(other);
+                }
+                None => builder.buffer.push('\\'),
+            },
+            '\n' => builder.flush_line(),
+            '[' => {
+                let Some(len) = input[offset..].find(']') else {
+                    return Err(MarkupError {
+                        offset,
+                        message: "unterminated tag".to_string(),
+                    });
+                };
+
+                let tag = &input[offset + 1..offset + len];
+
+                // skip over the tag and its closing bracket
+                for _ in tag.chars() {
+                    chars.next();
+                }
+                chars.next();
+
+                builder.flush_span();
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    match builder.stack.pop() {
+                        Some((open, _)) if name.is_empty() || name.trim() == open.trim() => (),
+                        Some((open, _)) => {
+                            return Err(MarkupError {
+                                offset,
+                                message: format!("`[{tag}]` does not close `[{open}]`"),
+                            })
+                        }
+                        None => {
+                            return Err(MarkupError {
+                                offset,
+                                message: format!("`[{tag}]` has no open tag to close"),
+                            })
+                        }
+                    }
+                } else {
+                    let style = parse_tag(tag, offset + 1)?;
+                    builder.stack.push((tag.to_string(), style));
+                }
+            }
+            c => builder.buffer.push(c),
+        }
+    }
+
+    builder.flush_line();
+
+    Ok(Text::from(builder.lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(input: &str) -> Vec<(String, Style)> {
+        parse(input)
+            .unwrap()
+            .lines
+            .into_iter()
+            .flat_map(|line| line.spans)
+            .map(|span| (span.content.into_owned(), span.style))
+            .collect()
+    }
+
+    fn error(input: &str) -> String {
+        parse(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn nested_tags_combine_and_close_innermost_first() {
+        let bold = Style::new().add_modifier(Modifier::BOLD);
+
+        assert_eq!(
+            spans("[bold][red on blue]a[/]b[/]c"),
+            vec![
+                ("a".to_string(), bold.fg(Color::Red).bg(Color::Blue)),
+                ("b".to_string(), bold),
+                ("c".to_string(), Style::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn named_close_matches_its_tag() {
+        assert_eq!(
+            spans("[u]x[/u]y"),
+            vec![
+                (
+                    "x".to_string(),
+                    Style::new().add_modifier(Modifier::UNDERLINED)
+                ),
+                ("y".to_string(), Style::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn escapes_produce_literal_brackets_and_backslashes() {
+        assert_eq!(
+            spans(r"\[not a tag] \\ \n"),
+            vec![(r"[not a tag] \ \n".to_string(), Style::new())]
+        );
+    }
+
+    #[test]
+    fn newlines_split_lines_and_keep_open_tags() {
+        let text = parse("[red]a\nb").unwrap();
+
+        assert_eq!(text.lines.len(), 2);
+        assert_eq!(text.lines[1].spans[0].style, Style::new().fg(Color::Red));
+    }
+
+    #[test]
+    fn mismatched_close_is_an_error() {
+        assert_eq!(
+            error("[bold]x[/red]"),
+            "markup error at byte 7: `[/red]` does not close `[bold]`"
+        );
+        assert_eq!(
+            error("x[/]"),
+            "markup error at byte 1: `[/]` has no open tag to close"
+        );
+    }
+
+    #[test]
+    fn unterminated_tag_is_an_error() {
+        assert_eq!(error("ab[bold"), "markup error at byte 2: unterminated tag");
+    }
+
+    #[test]
+    fn offsets_are_in_bytes_after_multibyte_text() {
+        // `é` is two bytes, so `[` is at byte 2, `bold` at 3 and `nope` at 8;
+        // an empty tag is reported where its contents would start, byte 3
+        assert_eq!(
+            error("é[bold nope]"),
+            "markup error at byte 8: unknown style `nope`"
+        );
+        assert_eq!(error("é[]"), "markup error at byte 3: empty tag");
+    }
+
+    #[test]
+    fn on_needs_a_known_color() {
+        assert_eq!(
+            error("[on]"),
+            "markup error at byte 1: `on` must be followed by a color"
+        );
+        assert_eq!(
+            error("[bold on blurple]"),
+            "markup error at byte 9: unknown color `blurple`"
+        );
+    }
+}
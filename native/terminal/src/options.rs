@@ -0,0 +1,24 @@
+use rustler::{Atom, Decoder, NifResult, Term};
+
+/// Looks up an optional key in an Elixir map or keyword list, decoding the
+/// value if present.
+pub(crate) fn get<'a, T: Decoder<'a>>(term: Term<'a>, key: Atom) -> NifResult<Option<T>> {
+    if term.is_list() {
+        let pairs: Vec<(Atom, Term<'a>)> = term.decode()?;
+
+        return match pairs.into_iter().find(|(k, _)| *k == key) {
+            Some((_, value)) => Ok(Some(value.decode()?)),
+            None => Ok(None),
+        };
+    }
+
+    match term.map_get(key) {
+        Ok(value) => Ok(Some(value.decode()?)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Whether a term can be read with [`get`].
+pub(crate) fn is_options(term: Term) -> bool {
+    term.is_map() || term.is_list()
+}
@@ -1,11 +1,29 @@
+use crate::terminal::nif_error;
 use ratatui::{
-    style::{Color, Modifier, Style},
-    text::{Span, Text},
+    layout::Rect,
+    text::Span,
     widgets::{Block, Borders, Paragraph, Wrap},
 };
-use rustler::ResourceArc;
+use rustler::{Decoder, NifResult, ResourceArc, Term};
+use std::sync::Mutex;
 
-pub(crate) struct ParagraphResource<'a>(ratatui::widgets::Paragraph<'a>);
+mod atoms {
+    rustler::atoms! {
+        title_style,
+        trim,
+
+        alignment,
+        scroll,
+        wrap,
+        style,
+        block,
+    }
+}
+
+pub(crate) struct ParagraphResource<'a>(
+    pub Mutex<Option<ratatui::widgets::Paragraph<'a>>>,
+    pub crate::theme::Themed<ratatui::widgets::Paragraph<'a>>,
+);
 
 impl<'a: 'static> rustler::Resource for ParagraphResource<'a> {}
 
@@ -14,42 +32,314 @@ impl<'a: 'static> rustler::Resource for ParagraphResource<'a> {}
 //     true
 // }
 
+/// Options for `paragraph_new/3`, a map or keyword list.
+struct ParagraphOptions {
+    alignment: Option<ratatui::layout::Alignment>,
+    scroll: (u16, u16),
+    wrap: Option<Wrap>,
+    style: Option<crate::style::Style>,
+}
+
+impl Decoder<'_> for ParagraphOptions {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if !crate::options::is_options(term) {
+            return Err(nif_error!(
+                "paragraph options must be a map or keyword list"
+            ));
+        }
+
+        let alignment =
+            crate::options::get::<crate::terminal::Alignment>(term, atoms::alignment())?
+                .map(|a| a.0);
+
+        let scroll = crate::options::get::<(u16, u16)>(term, atoms::scroll())?.unwrap_or((0, 0));
+
+        // wrapping without trimming unless told otherwise
+        let wrap = crate::options::get::<WrapOption>(term, atoms::wrap())?
+            .map_or(Some(Wrap { trim: false }), |w| w.0);
+
+        let style = crate::options::get::<crate::style::Style>(term, atoms::style())?;
+
+        Ok(ParagraphOptions {
+            alignment,
+            scroll,
+            wrap,
+            style,
+        })
+    }
+}
+
 #[rustler::nif(schedule = "DirtyIo", name = "paragraph_new")]
 fn new(
     block: rustler::ResourceArc<crate::block::BlockResource<'static>>,
-    text: String,
-) -> ResourceArc<ParagraphResource<'_>> {
-    let mut block = block.0.lock().unwrap();
+    text: crate::text::Text,
+    options: ParagraphOptions,
+) -> NifResult<ResourceArc<ParagraphResource<'static>>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, Paragraph::block);
 
-    let paragraph = Paragraph::new(Text::from(text.to_string()))
-        .block(block.take().unwrap())
-        .wrap(Wrap { trim: false });
+    let mut paragraph = Paragraph::new(text.0).block(block).scroll(options.scroll);
+
+    if let Some(alignment) = options.alignment {
+        paragraph = paragraph.alignment(alignment);
+    }
+
+    if let Some(wrap) = options.wrap {
+        paragraph = paragraph.wrap(wrap);
+    }
+
+    if let Some(style) = options.style {
+        paragraph = paragraph.style(style.0);
+        themed.set("style", &style, Paragraph::style);
+    }
+
+    Ok(rustler::ResourceArc::new(ParagraphResource(
+        Mutex::new(Some(paragraph)),
+        themed,
+    )))
+}
+
+/// Scrolls to `{y, x}`: `y` lines down and `x` columns right.
+#[rustler::nif(schedule = "DirtyIo", name = "paragraph_scroll")]
+fn scroll(
+    paragraph: ResourceArc<ParagraphResource<'static>>,
+    offset: (u16, u16),
+) -> ResourceArc<ParagraphResource<'static>> {
+    {
+        let mut lock = paragraph.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in paragraph_scroll");
+        *lock = Some(inner.scroll(offset))
+    }
+
+    paragraph
+}
+
+/// `false` disables wrapping, `true` wraps without trimming, and a map or
+/// keyword list like `[trim: true]` wraps with the given settings.
+pub(crate) struct WrapOption(pub Option<Wrap>);
+
+impl Decoder<'_> for WrapOption {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if let Ok(enabled) = term.decode::<bool>() {
+            return Ok(WrapOption(enabled.then_some(Wrap { trim: false })));
+        }
+
+        if !crate::options::is_options(term) {
+            return Err(nif_error!(
+                "wrap must be a boolean, a map or a keyword list"
+            ));
+        }
+
+        let trim = crate::options::get::<bool>(term, atoms::trim())?.unwrap_or(false);
+
+        Ok(WrapOption(Some(Wrap { trim })))
+    }
+}
+
+/// A width in cells, or `{chunks, index}` for the width of a layout slot.
+struct Width(u16);
+
+impl Decoder<'_> for Width {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if let Ok(width) = term.decode::<u16>() {
+            return Ok(Width(width));
+        }
+
+        let (chunks, index): (ResourceArc<crate::terminal::ChunksResource>, usize) = term
+            .decode()
+            .map_err(|_| nif_error!("width must be an integer or `{chunks, index}`"))?;
+
+        Ok(Width(chunks.area(index)?.width))
+    }
+}
 
-    rustler::ResourceArc::new(ParagraphResource(paragraph))
+/// Options for `paragraph_line_count/4`, a map or keyword list.
+struct LineCountOptions {
+    block: Option<Block<'static>>,
+}
+
+impl Decoder<'_> for LineCountOptions {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if !crate::options::is_options(term) {
+            return Err(nif_error!(
+                "line count options must be a map or keyword list"
+            ));
+        }
+
+        let block = crate::options::get::<ResourceArc<crate::block::BlockResource<'static>>>(
+            term,
+            atoms::block(),
+        )?
+        .map(|block| {
+            block
+                .0
+                .lock()
+                .unwrap()
+                .clone()
+                .ok_or_else(|| nif_error!("block has already been used by a widget"))
+        })
+        .transpose()?;
+
+        Ok(LineCountOptions { block })
+    }
+}
+
+/// How many lines `text` takes up at `width` with the given wrapping, and how
+/// wide its longest line is. With a block, the text is wrapped to the block's
+/// inner width and both numbers include its borders, padding and titles.
+fn measure(
+    text: ratatui::text::Text<'static>,
+    wrap: Option<Wrap>,
+    width: u16,
+    block: Option<&Block<'static>>,
+) -> (usize, usize) {
+    if width == 0 {
+        return (0, 0);
+    }
+
+    let mut paragraph = Paragraph::new(text);
+
+    if let Some(wrap) = wrap {
+        paragraph = paragraph.wrap(wrap);
+    }
+
+    // `Paragraph::line_count` wraps at the outer width even with a block, so
+    // measure against the block's inner area instead
+    let outer = Rect::new(0, 0, width, u16::MAX);
+    let inner = block.map_or(outer, |block| block.inner(outer));
+
+    let horizontal = usize::from(outer.width - inner.width);
+    let vertical = usize::from(outer.height - inner.height);
+
+    let line_count = paragraph.line_count(inner.width) + vertical;
+
+    (line_count, paragraph.line_width() + horizontal)
+}
+
+/// Returns `{line_count, line_width}` for `text` at `width`, matching what a
+/// paragraph built with the `:block` option's block will occupy.
+#[rustler::nif(schedule = "DirtyIo", name = "paragraph_line_count")]
+fn line_count(
+    text: crate::text::Text,
+    wrap: WrapOption,
+    width: Width,
+    options: LineCountOptions,
+) -> (usize, usize) {
+    measure(text.0, wrap.0, width.0, options.block.as_ref())
+}
+
+/// Options for `paragraph_render/5`, a map or keyword list.
+struct RenderOptions {
+    title_style: ratatui::style::Style,
+}
+
+impl Decoder<'_> for RenderOptions {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if !crate::options::is_options(term) {
+            return Err(nif_error!("render options must be a map or keyword list"));
+        }
+
+        let title_style = crate::options::get::<crate::style::Style>(term, atoms::title_style())?
+            .map_or_else(crate::block::default_title_style, |s| s.0);
+
+        Ok(RenderOptions { title_style })
+    }
 }
 
 #[rustler::nif(schedule = "DirtyIo", name = "paragraph_render")]
 fn render(
     terminal: ResourceArc<crate::terminal::TerminalResource>,
-    text: String,
+    text: crate::text::Text,
     chunks: ResourceArc<crate::terminal::ChunksResource>,
     index: usize,
-) {
+    options: RenderOptions,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
     // let widget = paragraph.0;
     // let chunk = chunks.0;
 
-    let block = Block::default().borders(Borders::ALL).title(Span::styled(
-        "Info",
-        Style::default()
-            .fg(Color::Cyan)
-            .add_modifier(Modifier::BOLD),
-    ));
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled("Info", options.title_style));
 
-    let paragraph = Paragraph::new(Text::from(text))
+    let paragraph = Paragraph::new(text.0)
         .block(block)
         .wrap(Wrap { trim: false });
 
     let mut t = terminal.terminal.lock().unwrap();
     let mut frame = t.get_frame();
-    frame.render_widget(paragraph, chunks.0[index]);
+    frame.render_widget(paragraph, area);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Padding;
+
+    fn text(s: &str) -> ratatui::text::Text<'static> {
+        ratatui::text::Text::from(s.to_string())
+    }
+
+    #[test]
+    fn unwrapped_lines_are_counted_as_given() {
+        assert_eq!(measure(text("hello world\nhi"), None, 5, None), (2, 11));
+    }
+
+    #[test]
+    fn wrapping_splits_lines_at_the_width() {
+        assert_eq!(
+            measure(text("hello world\nhi"), Some(Wrap { trim: false }), 5, None),
+            (3, 11)
+        );
+    }
+
+    #[test]
+    fn trimming_drops_leading_whitespace_from_wrapped_lines() {
+        assert_eq!(
+            measure(text("    abcd"), Some(Wrap { trim: false }), 4, None),
+            (2, 8)
+        );
+        assert_eq!(
+            measure(text("    abcd"), Some(Wrap { trim: true }), 4, None),
+            (1, 8)
+        );
+    }
+
+    #[test]
+    fn zero_width_measures_nothing() {
+        assert_eq!(
+            measure(text("hello"), Some(Wrap { trim: false }), 0, None),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn a_block_narrows_the_wrap_width_and_adds_its_overhead() {
+        let block = Block::bordered().padding(Padding::horizontal(1));
+
+        // 10 wide leaves 6 inside the borders and padding: "hello " / "world"
+        assert_eq!(
+            measure(
+                text("hello world"),
+                Some(Wrap { trim: false }),
+                10,
+                Some(&block)
+            ),
+            (4, 15)
+        );
+    }
+
+    #[test]
+    fn multibyte_lines_are_measured_in_cells() {
+        assert_eq!(
+            measure(text("日本語です"), Some(Wrap { trim: false }), 4, None),
+            (3, 10)
+        );
+        assert_eq!(measure(text("héllo"), None, 10, None), (1, 5));
+    }
 }
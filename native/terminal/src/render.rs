@@ -0,0 +1,238 @@
+//! One `render` NIF for every widget resource built on the Elixir side, so
+//! the builder API decides what gets drawn rather than each widget's own
+//! `*_render` NIF.
+
+use crate::terminal::{nif_error, TerminalResource};
+use ratatui::{layout::Rect, Frame};
+use rustler::{NifResult, ResourceArc, Term};
+
+/// Where to draw: `{chunks, index}` for a slot of a layout, or
+/// `{x, y, width, height}` for an explicit rectangle.
+struct Area(Rect);
+
+impl rustler::Decoder<'_> for Area {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if let Ok((chunks, index)) =
+            term.decode::<(ResourceArc<crate::terminal::ChunksResource>, usize)>()
+        {
+            return chunks.area(index).map(Area);
+        }
+
+        if let Ok((x, y, width, height)) = term.decode::<(u16, u16, u16, u16)>() {
+            return Ok(Area(Rect::new(x, y, width, height)));
+        }
+
+        Err(nif_error!(
+            "area must be `{chunks, index}` or `{x, y, width, height}`"
+        ))
+    }
+}
+
+/// Runs `f` against the current frame. Callers lock their widget first,
+/// matching the lock order of the widget-specific render NIFs.
+fn draw(terminal: &TerminalResource, area: Rect, f: impl FnOnce(&mut Frame, Rect)) {
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    // keep explicit rectangles from writing outside the buffer
+    let area = area.intersection(frame.area());
+    f(&mut frame, area)
+}
+
+/// Renders any widget resource. Stateful widgets (lists, tables and
+/// scrollbars) are given as `{widget, state}`.
+#[rustler::nif(schedule = "DirtyIo")]
+fn render(terminal: ResourceArc<TerminalResource>, widget: Term, area: Area) -> NifResult<()> {
+    let area = area.0;
+
+    if let Ok((widget, state)) = widget.decode::<(Term, Term)>() {
+        return render_stateful(&terminal, widget, state, area);
+    }
+
+    if let Ok(paragraph) =
+        widget.decode::<ResourceArc<crate::paragraph::ParagraphResource<'static>>>()
+    {
+        paragraph.1.restyle(&paragraph.0);
+        let paragraph = paragraph
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("paragraph has already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(paragraph, area)
+        });
+    } else if let Ok(block) = widget.decode::<ResourceArc<crate::block::BlockResource<'static>>>() {
+        block.1.restyle(&block.0);
+        let block = block
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("block has already been used by a widget"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(block, area)
+        });
+    } else if let Ok(list) = widget.decode::<ResourceArc<crate::list::ListResource>>() {
+        list.1.restyle(&list.0);
+        let list = list.0.lock().unwrap();
+        let list = list
+            .as_ref()
+            .ok_or_else(|| nif_error!("list has already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(list, area)
+        });
+    } else if let Ok(table) = widget.decode::<ResourceArc<crate::table::TableResource>>() {
+        table.1.restyle(&table.0);
+        let table = table.0.lock().unwrap();
+        let table = table
+            .as_ref()
+            .ok_or_else(|| nif_error!("table has already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(table, area)
+        });
+    } else if let Ok(tabs) = widget.decode::<ResourceArc<crate::tabs::TabsResource>>() {
+        tabs.1.restyle(&tabs.0);
+        let tabs = tabs
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("tabs have already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(tabs, area)
+        });
+    } else if let Ok(gauge) = widget.decode::<ResourceArc<crate::gauge::GaugeResource>>() {
+        gauge.1.restyle(&gauge.0);
+        let gauge = gauge
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("gauge has already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(gauge, area)
+        });
+    } else if let Ok(line_gauge) = widget.decode::<ResourceArc<crate::gauge::LineGaugeResource>>() {
+        line_gauge.1.restyle(&line_gauge.0);
+        let line_gauge = line_gauge
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("line gauge has already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(line_gauge, area)
+        });
+    } else if let Ok(sparkline) =
+        widget.decode::<ResourceArc<crate::sparkline::SparklineResource>>()
+    {
+        sparkline.1.restyle(&sparkline.0);
+        let sparkline = sparkline
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("sparkline has already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(sparkline, area)
+        });
+    } else if let Ok(bar_chart) = widget.decode::<ResourceArc<crate::bar_chart::BarChartResource>>()
+    {
+        bar_chart.1.restyle(&bar_chart.0);
+        let bar_chart = bar_chart
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("bar chart has already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(bar_chart, area)
+        });
+    } else if let Ok(chart) = widget.decode::<ResourceArc<crate::chart::ChartResource>>() {
+        let spec = chart.lock();
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(&*spec, area)
+        });
+    } else if let Ok(canvas) = widget.decode::<ResourceArc<crate::canvas::CanvasResource>>() {
+        let spec = canvas.lock();
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(&*spec, area)
+        });
+    } else if let Ok(calendar) = widget.decode::<ResourceArc<crate::calendar::CalendarResource>>() {
+        calendar.1.restyle(&calendar.0);
+        let calendar = calendar
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("calendar has already been used"))?;
+        draw(&terminal, area, |frame, area| {
+            frame.render_widget(calendar, area)
+        });
+    } else {
+        return Err(nif_error!(format!("cannot render {widget:?}")));
+    }
+
+    Ok(())
+}
+
+fn render_stateful(
+    terminal: &TerminalResource,
+    widget: Term,
+    state: Term,
+    area: Rect,
+) -> NifResult<()> {
+    if let Ok(list) = widget.decode::<ResourceArc<crate::list::ListResource>>() {
+        list.1.restyle(&list.0);
+        let state: ResourceArc<crate::list::ListStateResource> = state
+            .decode()
+            .map_err(|_| nif_error!("a list must be rendered with a list state"))?;
+
+        let list = list.0.lock().unwrap();
+        let list = list
+            .as_ref()
+            .ok_or_else(|| nif_error!("list has already been used"))?;
+        let mut state = state.0.lock().unwrap();
+        draw(terminal, area, |frame, area| {
+            frame.render_stateful_widget(list, area, &mut state)
+        });
+    } else if let Ok(table) = widget.decode::<ResourceArc<crate::table::TableResource>>() {
+        table.1.restyle(&table.0);
+        let state: ResourceArc<crate::table::TableStateResource> = state
+            .decode()
+            .map_err(|_| nif_error!("a table must be rendered with a table state"))?;
+
+        let table = table.0.lock().unwrap();
+        let table = table
+            .as_ref()
+            .ok_or_else(|| nif_error!("table has already been used"))?;
+        let mut state = state.0.lock().unwrap();
+        draw(terminal, area, |frame, area| {
+            frame.render_stateful_widget(table, area, &mut state)
+        });
+    } else if let Ok(scrollbar) =
+        widget.decode::<ResourceArc<crate::scrollbar::ScrollbarResource>>()
+    {
+        scrollbar.1.restyle(&scrollbar.0);
+        let state: ResourceArc<crate::scrollbar::ScrollbarStateResource> = state
+            .decode()
+            .map_err(|_| nif_error!("a scrollbar must be rendered with a scrollbar state"))?;
+
+        let scrollbar = scrollbar
+            .0
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| nif_error!("scrollbar has already been used"))?;
+        let mut state = state.0.lock().unwrap().state();
+        draw(terminal, area, |frame, area| {
+            frame.render_stateful_widget(scrollbar, area, &mut state)
+        });
+    } else {
+        return Err(nif_error!(format!(
+            "cannot render {widget:?} with state {state:?}"
+        )));
+    }
+
+    Ok(())
+}
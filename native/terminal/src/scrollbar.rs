@@ -0,0 +1,413 @@
+use crate::terminal::nif_error;
+use ratatui::symbols;
+use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+use rustler::{Decoder, NifResult, ResourceArc, Term};
+use std::sync::Mutex;
+
+mod atoms {
+    rustler::atoms! {
+        vertical_right,
+        vertical_left,
+        horizontal_bottom,
+        horizontal_top,
+
+        vertical,
+        double_vertical,
+        horizontal,
+        double_horizontal,
+    }
+}
+
+pub(crate) struct ScrollbarResource(
+    pub Mutex<Option<Scrollbar<'static>>>,
+    pub crate::theme::Themed<Scrollbar<'static>>,
+);
+
+impl rustler::Resource for ScrollbarResource {}
+
+/// Content length, viewport length and position of whatever the scrollbar
+/// is attached to. `ScrollbarState` doesn't expose its position, so the
+/// fields are kept here and the ratatui state is built when rendering.
+#[derive(Default)]
+pub(crate) struct ScrollbarStateSpec {
+    content_length: usize,
+    viewport_content_length: usize,
+    position: usize,
+}
+
+impl ScrollbarStateSpec {
+    /// Keeps `position` on the last item, like `next` and `last` do, so a
+    /// position set directly or left behind by shrinking content can't point
+    /// past the end.
+    fn clamp_position(&mut self) {
+        self.position = self.position.min(self.content_length.saturating_sub(1));
+    }
+
+    pub(crate) fn state(&self) -> ScrollbarState {
+        ScrollbarState::new(self.content_length)
+            .viewport_content_length(self.viewport_content_length)
+            .position(self.position)
+    }
+}
+
+pub(crate) struct ScrollbarStateResource(pub Mutex<ScrollbarStateSpec>);
+
+impl rustler::Resource for ScrollbarStateResource {}
+
+struct Orientation(ScrollbarOrientation);
+
+impl Decoder<'_> for Orientation {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::vertical_right() {
+            Ok(Orientation(ScrollbarOrientation::VerticalRight))
+        } else if term == atoms::vertical_left() {
+            Ok(Orientation(ScrollbarOrientation::VerticalLeft))
+        } else if term == atoms::horizontal_bottom() {
+            Ok(Orientation(ScrollbarOrientation::HorizontalBottom))
+        } else if term == atoms::horizontal_top() {
+            Ok(Orientation(ScrollbarOrientation::HorizontalTop))
+        } else {
+            Err(nif_error!(
+                "orientation must be `:vertical_right`, `:vertical_left`, `:horizontal_bottom` or `:horizontal_top`"
+            ))
+        }
+    }
+}
+
+struct SymbolSet(symbols::scrollbar::Set);
+
+impl Decoder<'_> for SymbolSet {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::vertical() {
+            Ok(SymbolSet(symbols::scrollbar::VERTICAL))
+        } else if term == atoms::double_vertical() {
+            Ok(SymbolSet(symbols::scrollbar::DOUBLE_VERTICAL))
+        } else if term == atoms::horizontal() {
+            Ok(SymbolSet(symbols::scrollbar::HORIZONTAL))
+        } else if term == atoms::double_horizontal() {
+            Ok(SymbolSet(symbols::scrollbar::DOUBLE_HORIZONTAL))
+        } else {
+            Err(nif_error!(
+                "symbols must be `:vertical`, `:double_vertical`, `:horizontal` or `:double_horizontal`"
+            ))
+        }
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_new(orientation: Orientation) -> ResourceArc<ScrollbarResource> {
+    ResourceArc::new(ScrollbarResource(
+        Mutex::new(Some(Scrollbar::new(orientation.0))),
+        crate::theme::Themed::default(),
+    ))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_symbols(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    symbols: SymbolSet,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_symbols");
+        *lock = Some(inner.symbols(symbols.0))
+    }
+
+    scrollbar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_thumb_symbol(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    symbol: &str,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_thumb_symbol");
+        *lock = Some(inner.thumb_symbol(crate::intern::intern(symbol)))
+    }
+
+    scrollbar
+}
+
+/// `nil` hides the track.
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_track_symbol(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    symbol: Option<&str>,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_track_symbol");
+        *lock = Some(inner.track_symbol(symbol.map(crate::intern::intern)))
+    }
+
+    scrollbar
+}
+
+/// `nil` hides the begin symbol.
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_begin_symbol(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    symbol: Option<&str>,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_begin_symbol");
+        *lock = Some(inner.begin_symbol(symbol.map(crate::intern::intern)))
+    }
+
+    scrollbar
+}
+
+/// `nil` hides the end symbol.
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_end_symbol(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    symbol: Option<&str>,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_end_symbol");
+        *lock = Some(inner.end_symbol(symbol.map(crate::intern::intern)))
+    }
+
+    scrollbar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_style(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_style");
+        *lock = Some(inner.style(style.0))
+    }
+
+    scrollbar.1.set("style", &style, Scrollbar::style);
+
+    scrollbar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_thumb_style(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_thumb_style");
+        *lock = Some(inner.thumb_style(style.0))
+    }
+
+    scrollbar
+        .1
+        .set("thumb_style", &style, Scrollbar::thumb_style);
+
+    scrollbar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_track_style(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_track_style");
+        *lock = Some(inner.track_style(style.0))
+    }
+
+    scrollbar
+        .1
+        .set("track_style", &style, Scrollbar::track_style);
+
+    scrollbar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_begin_style(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_begin_style");
+        *lock = Some(inner.begin_style(style.0))
+    }
+
+    scrollbar
+        .1
+        .set("begin_style", &style, Scrollbar::begin_style);
+
+    scrollbar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_end_style(
+    scrollbar: ResourceArc<ScrollbarResource>,
+    style: crate::style::Style,
+) -> ResourceArc<ScrollbarResource> {
+    {
+        let mut lock = scrollbar.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in scrollbar_end_style");
+        *lock = Some(inner.end_style(style.0))
+    }
+
+    scrollbar.1.set("end_style", &style, Scrollbar::end_style);
+
+    scrollbar
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    scrollbar: ResourceArc<ScrollbarResource>,
+    state: ResourceArc<ScrollbarStateResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    scrollbar.1.restyle(&scrollbar.0);
+    let scrollbar = scrollbar
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| nif_error!("scrollbar has already been used"))?;
+    let mut state = state.0.lock().unwrap().state();
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_stateful_widget(scrollbar, area, &mut state);
+
+    Ok(())
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_new(content_length: usize) -> ResourceArc<ScrollbarStateResource> {
+    ResourceArc::new(ScrollbarStateResource(Mutex::new(ScrollbarStateSpec {
+        content_length,
+        ..Default::default()
+    })))
+}
+
+/// Shrinking the content pulls the position back onto the last item.
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_content_length(
+    state: ResourceArc<ScrollbarStateResource>,
+    content_length: usize,
+) -> ResourceArc<ScrollbarStateResource> {
+    {
+        let mut lock = state.0.lock().unwrap();
+        lock.content_length = content_length;
+        lock.clamp_position();
+    }
+
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_viewport_content_length(
+    state: ResourceArc<ScrollbarStateResource>,
+    viewport_content_length: usize,
+) -> ResourceArc<ScrollbarStateResource> {
+    state.0.lock().unwrap().viewport_content_length = viewport_content_length;
+
+    state
+}
+
+/// Positions past the content are clamped to the last item.
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_position(
+    state: ResourceArc<ScrollbarStateResource>,
+    position: usize,
+) -> ResourceArc<ScrollbarStateResource> {
+    {
+        let mut lock = state.0.lock().unwrap();
+        lock.position = position;
+        lock.clamp_position();
+    }
+
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_next(
+    state: ResourceArc<ScrollbarStateResource>,
+) -> ResourceArc<ScrollbarStateResource> {
+    {
+        let mut lock = state.0.lock().unwrap();
+        lock.position = lock.position.saturating_add(1);
+        lock.clamp_position();
+    }
+
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_prev(
+    state: ResourceArc<ScrollbarStateResource>,
+) -> ResourceArc<ScrollbarStateResource> {
+    {
+        let mut lock = state.0.lock().unwrap();
+        lock.position = lock.position.saturating_sub(1);
+    }
+
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_first(
+    state: ResourceArc<ScrollbarStateResource>,
+) -> ResourceArc<ScrollbarStateResource> {
+    state.0.lock().unwrap().position = 0;
+
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_last(
+    state: ResourceArc<ScrollbarStateResource>,
+) -> ResourceArc<ScrollbarStateResource> {
+    {
+        let mut lock = state.0.lock().unwrap();
+        lock.position = lock.content_length.saturating_sub(1);
+    }
+
+    state
+}
+
+/// The current position, always less than the content length (or 0 with no
+/// content).
+#[rustler::nif(schedule = "DirtyIo")]
+fn scrollbar_state_get_position(state: ResourceArc<ScrollbarStateResource>) -> usize {
+    state.0.lock().unwrap().position
+}
@@ -0,0 +1,177 @@
+use crate::terminal::nif_error;
+use ratatui::symbols;
+use ratatui::widgets::{RenderDirection, Sparkline};
+use rustler::{Decoder, NifResult, ResourceArc, Term};
+use std::sync::Mutex;
+
+mod atoms {
+    rustler::atoms! {
+        nine_levels,
+        three_levels,
+
+        left_to_right,
+        right_to_left,
+    }
+}
+
+pub(crate) struct SparklineResource(
+    pub Mutex<Option<Sparkline<'static>>>,
+    pub crate::theme::Themed<Sparkline<'static>>,
+);
+
+impl rustler::Resource for SparklineResource {}
+
+struct BarSet(symbols::bar::Set);
+
+impl Decoder<'_> for BarSet {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::nine_levels() {
+            Ok(BarSet(symbols::bar::NINE_LEVELS))
+        } else if term == atoms::three_levels() {
+            Ok(BarSet(symbols::bar::THREE_LEVELS))
+        } else {
+            Err(nif_error!(
+                "bar set must be `:nine_levels` or `:three_levels`"
+            ))
+        }
+    }
+}
+
+struct Direction(RenderDirection);
+
+impl Decoder<'_> for Direction {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::left_to_right() {
+            Ok(Direction(RenderDirection::LeftToRight))
+        } else if term == atoms::right_to_left() {
+            Ok(Direction(RenderDirection::RightToLeft))
+        } else {
+            Err(nif_error!(
+                "direction must be `:left_to_right` or `:right_to_left`"
+            ))
+        }
+    }
+}
+
+/// `nil` entries in `data` are drawn as absent values.
+#[rustler::nif(schedule = "DirtyIo")]
+fn sparkline_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+    data: Vec<Option<u64>>,
+) -> NifResult<ResourceArc<SparklineResource>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, Sparkline::block);
+
+    let sparkline = Sparkline::default().data(data).block(block);
+
+    Ok(ResourceArc::new(SparklineResource(
+        Mutex::new(Some(sparkline)),
+        themed,
+    )))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn sparkline_data(
+    sparkline: ResourceArc<SparklineResource>,
+    data: Vec<Option<u64>>,
+) -> ResourceArc<SparklineResource> {
+    {
+        let mut lock = sparkline.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in sparkline_data");
+        *lock = Some(inner.data(data))
+    }
+
+    sparkline
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn sparkline_max(
+    sparkline: ResourceArc<SparklineResource>,
+    max: u64,
+) -> ResourceArc<SparklineResource> {
+    {
+        let mut lock = sparkline.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in sparkline_max");
+        *lock = Some(inner.max(max))
+    }
+
+    sparkline
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn sparkline_bar_set(
+    sparkline: ResourceArc<SparklineResource>,
+    bar_set: BarSet,
+) -> ResourceArc<SparklineResource> {
+    {
+        let mut lock = sparkline.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in sparkline_bar_set");
+        *lock = Some(inner.bar_set(bar_set.0))
+    }
+
+    sparkline
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn sparkline_direction(
+    sparkline: ResourceArc<SparklineResource>,
+    direction: Direction,
+) -> ResourceArc<SparklineResource> {
+    {
+        let mut lock = sparkline.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in sparkline_direction");
+        *lock = Some(inner.direction(direction.0))
+    }
+
+    sparkline
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn sparkline_style(
+    sparkline: ResourceArc<SparklineResource>,
+    style: crate::style::Style,
+) -> ResourceArc<SparklineResource> {
+    {
+        let mut lock = sparkline.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in sparkline_style");
+        *lock = Some(inner.style(style.0))
+    }
+
+    sparkline.1.set("style", &style, Sparkline::style);
+
+    sparkline
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn sparkline_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    sparkline: ResourceArc<SparklineResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    sparkline.1.restyle(&sparkline.0);
+    let sparkline = sparkline
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| nif_error!("sparkline has already been used"))?;
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(sparkline, area);
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use crate::terminal::nif_error;
+use ratatui::style::Modifier;
+use rustler::{Decoder, NifResult, Term};
+
+mod atoms {
+    rustler::atoms! {
+        fg,
+        bg,
+        underline_color,
+        modifiers,
+        remove_modifiers,
+
+        bold,
+        dim,
+        italic,
+        underlined,
+        slow_blink,
+        rapid_blink,
+        reversed,
+        hidden,
+        crossed_out,
+    }
+}
+
+struct ModifierName(Modifier);
+
+impl Decoder<'_> for ModifierName {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let modifier: rustler::Atom = term.decode()?;
+
+        let named = [
+            (atoms::bold(), Modifier::BOLD),
+            (atoms::dim(), Modifier::DIM),
+            (atoms::italic(), Modifier::ITALIC),
+            (atoms::underlined(), Modifier::UNDERLINED),
+            (atoms::slow_blink(), Modifier::SLOW_BLINK),
+            (atoms::rapid_blink(), Modifier::RAPID_BLINK),
+            (atoms::reversed(), Modifier::REVERSED),
+            (atoms::hidden(), Modifier::HIDDEN),
+            (atoms::crossed_out(), Modifier::CROSSED_OUT),
+        ];
+
+        named
+            .into_iter()
+            .find(|(name, _)| *name == modifier)
+            .map(|(_, modifier)| ModifierName(modifier))
+            .ok_or_else(|| nif_error!(format!("unknown modifier {term:?}")))
+    }
+}
+
+/// Decodes a style from a map or keyword list like
+/// `%{fg: :red, bg: {:rgb, 10, 20, 30}, underline_color: :blue, modifiers: [:bold]}`.
+/// Every key is optional; `:remove_modifiers` clears modifiers when the style
+/// is patched over another one. An atom names a style in the active theme;
+/// the name is kept alongside the resolved style so widgets can look it up
+/// again when they are drawn (see `crate::theme::Themed`).
+pub(crate) struct Style(pub ratatui::style::Style, pub Option<&'static str>);
+
+impl Decoder<'_> for Style {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if term.is_atom() {
+            let name = term.atom_to_string()?;
+            return crate::theme::lookup(&name)
+                .map(|style| Style(style, Some(crate::intern::intern(&name))))
+                .ok_or_else(|| nif_error!(format!("no style named :{name} in the active theme")));
+        }
+
+        if !crate::options::is_options(term) {
+            return Err(nif_error!(
+                "style must be a map, a keyword list or a theme style name"
+            ));
+        }
+
+        let mut style = ratatui::style::Style::default();
+
+        if let Some(fg) = crate::options::get::<crate::color::Color>(term, atoms::fg())? {
+            style = style.fg(fg.0);
+        }
+
+        if let Some(bg) = crate::options::get::<crate::color::Color>(term, atoms::bg())? {
+            style = style.bg(bg.0);
+        }
+
+        if let Some(color) =
+            crate::options::get::<crate::color::Color>(term, atoms::underline_color())?
+        {
+            style = style.underline_color(color.0);
+        }
+
+        if let Some(modifiers) = crate::options::get::<Vec<ModifierName>>(term, atoms::modifiers())?
+        {
+            for modifier in modifiers {
+                style = style.add_modifier(modifier.0);
+            }
+        }
+
+        if let Some(modifiers) =
+            crate::options::get::<Vec<ModifierName>>(term, atoms::remove_modifiers())?
+        {
+            for modifier in modifiers {
+                style = style.remove_modifier(modifier.0);
+            }
+        }
+
+        Ok(Style(style, None))
+    }
+}
@@ -0,0 +1,197 @@
+use crate::terminal::nif_error;
+use ratatui::widgets::{Cell, Row, Table, TableState};
+use rustler::{NifResult, ResourceArc};
+use std::sync::Mutex;
+
+pub(crate) struct TableResource(
+    pub Mutex<Option<Table<'static>>>,
+    pub crate::theme::Themed<Table<'static>>,
+);
+
+impl rustler::Resource for TableResource {}
+
+/// Selected row and scroll offset for a table, kept across frames.
+pub(crate) struct TableStateResource(pub Mutex<TableState>);
+
+impl rustler::Resource for TableStateResource {}
+
+fn row(cells: Vec<crate::text::Text>) -> Row<'static> {
+    Row::new(cells.into_iter().map(|cell| Cell::from(cell.0)))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+    rows: Vec<Vec<crate::text::Text>>,
+    widths: Vec<crate::terminal::Constraint>,
+) -> NifResult<ResourceArc<TableResource>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, Table::block);
+
+    let table = Table::new(rows.into_iter().map(row), widths.into_iter().map(|c| c.0)).block(block);
+
+    Ok(ResourceArc::new(TableResource(
+        Mutex::new(Some(table)),
+        themed,
+    )))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_header(
+    table: ResourceArc<TableResource>,
+    header: Vec<crate::text::Text>,
+) -> ResourceArc<TableResource> {
+    {
+        let mut lock = table.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in table_header");
+        *lock = Some(inner.header(row(header)))
+    }
+
+    table
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_footer(
+    table: ResourceArc<TableResource>,
+    footer: Vec<crate::text::Text>,
+) -> ResourceArc<TableResource> {
+    {
+        let mut lock = table.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in table_footer");
+        *lock = Some(inner.footer(row(footer)))
+    }
+
+    table
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_column_spacing(
+    table: ResourceArc<TableResource>,
+    spacing: u16,
+) -> ResourceArc<TableResource> {
+    {
+        let mut lock = table.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in table_column_spacing");
+        *lock = Some(inner.column_spacing(spacing))
+    }
+
+    table
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_row_highlight_style(
+    table: ResourceArc<TableResource>,
+    style: crate::style::Style,
+) -> ResourceArc<TableResource> {
+    {
+        let mut lock = table.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in table_row_highlight_style");
+        *lock = Some(inner.row_highlight_style(style.0))
+    }
+
+    table
+        .1
+        .set("row_highlight_style", &style, Table::row_highlight_style);
+
+    table
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_highlight_symbol(
+    table: ResourceArc<TableResource>,
+    symbol: String,
+) -> ResourceArc<TableResource> {
+    {
+        let mut lock = table.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in table_highlight_symbol");
+        *lock = Some(inner.highlight_symbol(symbol))
+    }
+
+    table
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    table: ResourceArc<TableResource>,
+    state: ResourceArc<TableStateResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    table.1.restyle(&table.0);
+    let table = table.0.lock().unwrap();
+    let table = table
+        .as_ref()
+        .ok_or_else(|| nif_error!("table has already been used"))?;
+    let mut state = state.0.lock().unwrap();
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_stateful_widget(table, area, &mut state);
+
+    Ok(())
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_state_new() -> ResourceArc<TableStateResource> {
+    ResourceArc::new(TableStateResource(Mutex::new(TableState::default())))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_state_select(
+    state: ResourceArc<TableStateResource>,
+    index: Option<usize>,
+) -> ResourceArc<TableStateResource> {
+    state.0.lock().unwrap().select(index);
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_state_select_next(
+    state: ResourceArc<TableStateResource>,
+) -> ResourceArc<TableStateResource> {
+    state.0.lock().unwrap().select_next();
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_state_select_previous(
+    state: ResourceArc<TableStateResource>,
+) -> ResourceArc<TableStateResource> {
+    state.0.lock().unwrap().select_previous();
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_state_select_first(
+    state: ResourceArc<TableStateResource>,
+) -> ResourceArc<TableStateResource> {
+    state.0.lock().unwrap().select_first();
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_state_select_last(
+    state: ResourceArc<TableStateResource>,
+) -> ResourceArc<TableStateResource> {
+    state.0.lock().unwrap().select_last();
+    state
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_state_selected(state: ResourceArc<TableStateResource>) -> Option<usize> {
+    state.0.lock().unwrap().selected()
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn table_state_offset(state: ResourceArc<TableStateResource>) -> usize {
+    state.0.lock().unwrap().offset()
+}
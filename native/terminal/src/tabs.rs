@@ -0,0 +1,107 @@
+use crate::terminal::nif_error;
+use ratatui::widgets::Tabs;
+use rustler::{NifResult, ResourceArc};
+use std::sync::Mutex;
+
+pub(crate) struct TabsResource(
+    pub Mutex<Option<Tabs<'static>>>,
+    pub crate::theme::Themed<Tabs<'static>>,
+);
+
+impl rustler::Resource for TabsResource {}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn tabs_new(
+    block: ResourceArc<crate::block::BlockResource<'static>>,
+    titles: Vec<crate::text::Line>,
+) -> NifResult<ResourceArc<TabsResource>> {
+    let (block, block_theme) = block.take()?;
+    let themed = crate::theme::Themed::with_block(&block, block_theme, Tabs::block);
+
+    let tabs = Tabs::new(titles.into_iter().map(|title| title.0)).block(block);
+
+    Ok(ResourceArc::new(TabsResource(
+        Mutex::new(Some(tabs)),
+        themed,
+    )))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn tabs_select(tabs: ResourceArc<TabsResource>, index: Option<usize>) -> ResourceArc<TabsResource> {
+    {
+        let mut lock = tabs.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in tabs_select");
+        *lock = Some(inner.select(index))
+    }
+
+    tabs
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn tabs_divider(tabs: ResourceArc<TabsResource>, divider: String) -> ResourceArc<TabsResource> {
+    {
+        let mut lock = tabs.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in tabs_divider");
+        *lock = Some(inner.divider(divider))
+    }
+
+    tabs
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn tabs_style(
+    tabs: ResourceArc<TabsResource>,
+    style: crate::style::Style,
+) -> ResourceArc<TabsResource> {
+    {
+        let mut lock = tabs.0.lock().unwrap();
+        let inner = lock.take().expect("must be able to take in tabs_style");
+        *lock = Some(inner.style(style.0))
+    }
+
+    tabs.1.set("style", &style, Tabs::style);
+
+    tabs
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn tabs_highlight_style(
+    tabs: ResourceArc<TabsResource>,
+    style: crate::style::Style,
+) -> ResourceArc<TabsResource> {
+    {
+        let mut lock = tabs.0.lock().unwrap();
+        let inner = lock
+            .take()
+            .expect("must be able to take in tabs_highlight_style");
+        *lock = Some(inner.highlight_style(style.0))
+    }
+
+    tabs.1.set("highlight_style", &style, Tabs::highlight_style);
+
+    tabs
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn tabs_render(
+    terminal: ResourceArc<crate::terminal::TerminalResource>,
+    tabs: ResourceArc<TabsResource>,
+    chunks: ResourceArc<crate::terminal::ChunksResource>,
+    index: usize,
+) -> NifResult<()> {
+    let area = chunks.area(index)?;
+
+    tabs.1.restyle(&tabs.0);
+    let tabs = tabs
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| nif_error!("tabs have already been used"))?;
+
+    let mut t = terminal.terminal.lock().unwrap();
+    let mut frame = t.get_frame();
+    frame.render_widget(tabs, area);
+
+    Ok(())
+}
@@ -1,8 +1,6 @@
+use crate::color::ColorSupport;
 use ratatui::layout::{Direction, Layout, Rect};
 use ratatui::prelude::{Backend, CrosstermBackend};
-use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::{Span, Text};
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use ratatui::Terminal;
 use rustler::{Decoder, Env, NifResult, Resource, ResourceArc, Term};
 use std::collections::HashMap;
@@ -68,13 +66,25 @@ mod atoms {
         kind,
         state,
 
+        center,
+
+        truecolor,
+        ansi256,
+        ansi16,
+
         percentage,
+        length,
+        min,
+        max,
+        fill,
+        ratio,
 
     }
 }
 
 pub(crate) struct TerminalResource {
     pub terminal: Mutex<Terminal<CrosstermBackend<std::io::Stdout>>>,
+    pub color_support: Mutex<ColorSupport>,
 }
 
 impl Resource for TerminalResource {
@@ -96,18 +106,14 @@ impl Resource for TerminalResource {
     fn down<'a>(&'a self, _env: Env<'a>, _pid: rustler::LocalPid, _monitor: rustler::Monitor) {}
 }
 
-struct NakedTerminalResource {
-    pub terminal: Terminal<CrosstermBackend<std::io::Stdout>>,
-}
-
-impl Resource for NakedTerminalResource {}
-
 macro_rules! nif_error {
     ($term:expr) => {
         rustler::Error::Term(Box::new($term.to_string()))
     };
 }
 
+pub(crate) use nif_error;
+
 #[derive(PartialEq)]
 enum Mode {
     Active,
@@ -183,6 +189,7 @@ fn terminal_new(
         atoms::ok(),
         ResourceArc::new(TerminalResource {
             terminal: Mutex::new(terminal),
+            color_support: Mutex::new(ColorSupport::detect()),
         }),
     ))
 }
@@ -367,6 +374,7 @@ impl rustler::Encoder for KeyEventKind {
     }
 }
 
+#[allow(dead_code)]
 struct KeyEventState(crossterm::event::KeyEventState);
 
 impl From<crossterm::event::KeyEventState> for KeyEventState {
@@ -387,18 +395,63 @@ fn terminal_read_event() -> NifResult<(rustler::Atom, Event)> {
     Ok((atoms::ok(), event.into()))
 }
 
-struct Constraint(ratatui::layout::Constraint);
+pub(crate) struct Constraint(pub ratatui::layout::Constraint);
 
 impl<'a> rustler::Decoder<'a> for Constraint {
     fn decode(term: Term<'a>) -> NifResult<Self> {
+        if let Ok((kind, numerator, denominator)) = term.decode::<(rustler::Atom, u32, u32)>() {
+            return if kind == atoms::ratio() {
+                Ok(Constraint(ratatui::layout::Constraint::Ratio(
+                    numerator,
+                    denominator,
+                )))
+            } else {
+                Err(nif_error!(
+                    "constraint must be `{:ratio, numerator, denominator}`"
+                ))
+            };
+        }
+
         let constraint: (rustler::Atom, u16) = term.decode()?;
 
         if constraint.0 == atoms::percentage() {
             Ok(Constraint(ratatui::layout::Constraint::Percentage(
                 constraint.1,
             )))
+        } else if constraint.0 == atoms::length() {
+            Ok(Constraint(ratatui::layout::Constraint::Length(
+                constraint.1,
+            )))
+        } else if constraint.0 == atoms::min() {
+            Ok(Constraint(ratatui::layout::Constraint::Min(constraint.1)))
+        } else if constraint.0 == atoms::max() {
+            Ok(Constraint(ratatui::layout::Constraint::Max(constraint.1)))
+        } else if constraint.0 == atoms::fill() {
+            Ok(Constraint(ratatui::layout::Constraint::Fill(constraint.1)))
+        } else {
+            Err(nif_error!(
+                "constraint must be one of `:percentage`, `:length`, `:min`, `:max`, `:fill` or `:ratio`"
+            ))
+        }
+    }
+}
+
+pub(crate) struct Alignment(pub ratatui::layout::Alignment);
+
+impl Decoder<'_> for Alignment {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::left() {
+            Ok(Alignment(ratatui::layout::Alignment::Left))
+        } else if term == atoms::center() {
+            Ok(Alignment(ratatui::layout::Alignment::Center))
+        } else if term == atoms::right() {
+            Ok(Alignment(ratatui::layout::Alignment::Right))
         } else {
-            Err(nif_error!("something bad"))
+            Err(nif_error!(
+                "alignment must be `:left`, `:center` or `:right`"
+            ))
         }
     }
 }
@@ -410,6 +463,17 @@ impl Resource for ChunksResource {
     const IMPLEMENTS_DOWN: bool = false;
 }
 
+impl ChunksResource {
+    /// The rect at `index`. Render NIFs look this up before locking the
+    /// terminal, so a bad index can't panic while the lock is held.
+    pub(crate) fn area(&self, index: usize) -> NifResult<Rect> {
+        self.0
+            .get(index)
+            .copied()
+            .ok_or_else(|| nif_error!(format!("chunk index {index} is out of range")))
+    }
+}
+
 #[rustler::nif(schedule = "DirtyIo")]
 fn chunks_new(
     terminal: ResourceArc<TerminalResource>,
@@ -475,6 +539,64 @@ fn chunks_new(
 //     Ok(atoms::ok())
 // }
 
+impl rustler::Encoder for ColorSupport {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        match self {
+            ColorSupport::TrueColor => atoms::truecolor().to_term(env),
+            ColorSupport::Ansi256 => atoms::ansi256().to_term(env),
+            ColorSupport::Ansi16 => atoms::ansi16().to_term(env),
+            ColorSupport::None => atoms::none().to_term(env),
+        }
+    }
+}
+
+impl Decoder<'_> for ColorSupport {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        let term: rustler::Atom = term.decode()?;
+
+        if term == atoms::truecolor() {
+            Ok(ColorSupport::TrueColor)
+        } else if term == atoms::ansi256() {
+            Ok(ColorSupport::Ansi256)
+        } else if term == atoms::ansi16() {
+            Ok(ColorSupport::Ansi16)
+        } else if term == atoms::none() {
+            Ok(ColorSupport::None)
+        } else {
+            Err(nif_error!(
+                "color support must be `:truecolor`, `:ansi256`, `:ansi16` or `:none`"
+            ))
+        }
+    }
+}
+
+// Rewrites the colors in the frame about to be flushed to ones the terminal can show.
+fn downgrade_colors(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    color_support: ColorSupport,
+) {
+    if color_support == ColorSupport::TrueColor {
+        return;
+    }
+
+    for cell in &mut terminal.current_buffer_mut().content {
+        cell.fg = color_support.downgrade(cell.fg);
+        cell.bg = color_support.downgrade(cell.bg);
+        cell.underline_color = color_support.downgrade(cell.underline_color);
+    }
+}
+
+#[rustler::nif(schedule = "DirtyIo", name = "terminal_color_support")]
+fn color_support(terminal: ResourceArc<TerminalResource>) -> ColorSupport {
+    *terminal.color_support.lock().unwrap()
+}
+
+/// Overrides the detected color support.
+#[rustler::nif(schedule = "DirtyIo", name = "terminal_set_color_support")]
+fn set_color_support(terminal: ResourceArc<TerminalResource>, color_support: ColorSupport) {
+    *terminal.color_support.lock().unwrap() = color_support;
+}
+
 #[rustler::nif(schedule = "DirtyIo", name = "terminal_predraw")]
 fn predraw(terminal: ResourceArc<TerminalResource>) -> NifResult<()> {
     let mut terminal = terminal.terminal.lock().unwrap();
@@ -482,8 +604,8 @@ fn predraw(terminal: ResourceArc<TerminalResource>) -> NifResult<()> {
 }
 
 #[rustler::nif(schedule = "DirtyIo", name = "terminal_postdraw")]
-fn postdraw(terminal: ResourceArc<TerminalResource>) -> NifResult<()> {
-    let mut terminal = terminal.terminal.lock().unwrap();
+fn postdraw(terminal_resource: ResourceArc<TerminalResource>) -> NifResult<()> {
+    let mut terminal = terminal_resource.terminal.lock().unwrap();
 
     // let frame = terminal.get_frame();
 
@@ -495,6 +617,11 @@ fn postdraw(terminal: ResourceArc<TerminalResource>) -> NifResult<()> {
         .get_cursor_position()
         .map_err(|e| nif_error!(e));
 
+    downgrade_colors(
+        &mut terminal,
+        *terminal_resource.color_support.lock().unwrap(),
+    );
+
     // Draw to stdout
     terminal.flush().map_err(|e| nif_error!(e))?;
 
@@ -565,8 +692,12 @@ fn get_cursor_position(terminal: ResourceArc<TerminalResource>) -> NifResult<(u1
 
 // def flush(_terminal), do: :erlang.nif_error(:nif_not_loaded)
 #[rustler::nif(schedule = "DirtyIo", name = "terminal_flush")]
-fn flush(terminal: ResourceArc<TerminalResource>) -> NifResult<()> {
-    let mut terminal = terminal.terminal.lock().unwrap();
+fn flush(terminal_resource: ResourceArc<TerminalResource>) -> NifResult<()> {
+    let mut terminal = terminal_resource.terminal.lock().unwrap();
+    downgrade_colors(
+        &mut terminal,
+        *terminal_resource.color_support.lock().unwrap(),
+    );
     terminal.flush().map_err(|e| nif_error!(e))
 }
 
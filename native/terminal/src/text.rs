@@ -0,0 +1,135 @@
+use crate::terminal::nif_error;
+use ansi_to_tui::IntoText;
+use rustler::{Binary, Decoder, NifResult, Term};
+
+mod atoms {
+    rustler::atoms! {
+        spans,
+        alignment,
+        style,
+
+        ansi,
+        markup,
+    }
+}
+
+/// Parses `{:markup, binary}`, or returns `None` for any other term.
+fn markup(term: Term) -> NifResult<Option<ratatui::text::Text<'static>>> {
+    match term.decode::<(rustler::Atom, String)>() {
+        Ok((tag, source)) if tag == atoms::markup() => crate::markup::parse(&source)
+            .map(Some)
+            .map_err(|e| nif_error!(e)),
+        _ => Ok(None),
+    }
+}
+
+/// A binary, or `{binary, style}` for a styled span.
+pub(crate) struct Span(pub ratatui::text::Span<'static>);
+
+impl Decoder<'_> for Span {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if term.is_binary() {
+            return Ok(Span(ratatui::text::Span::raw(term.decode::<String>()?)));
+        }
+
+        let (content, style): (String, crate::style::Style) = term
+            .decode()
+            .map_err(|_| nif_error!("span must be a binary or `{binary, style}`"))?;
+
+        Ok(Span(ratatui::text::Span::styled(content, style.0)))
+    }
+}
+
+/// A binary, a list of spans, `{:markup, binary}` without newlines, or
+/// `%{spans: binary_or_spans, alignment: alignment, style: style}`
+/// where `:alignment` and `:style` are optional.
+pub(crate) struct Line(pub ratatui::text::Line<'static>);
+
+impl Decoder<'_> for Line {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if term.is_binary() {
+            return Ok(Line(ratatui::text::Line::raw(term.decode::<String>()?)));
+        }
+
+        if term.is_list() {
+            let spans: Vec<Span> = term.decode()?;
+            return Ok(Line(ratatui::text::Line::from(
+                spans.into_iter().map(|s| s.0).collect::<Vec<_>>(),
+            )));
+        }
+
+        if let Some(text) = markup(term)? {
+            if text.lines.len() > 1 {
+                return Err(nif_error!("line markup must not contain newlines"));
+            }
+
+            return Ok(Line(text.lines.into_iter().next().unwrap_or_default()));
+        }
+
+        if !term.is_map() {
+            return Err(nif_error!(
+                "line must be a binary, a list of spans, `{:markup, binary}` or a map"
+            ));
+        }
+
+        let mut line = crate::options::get::<Line>(term, atoms::spans())?
+            .ok_or_else(|| nif_error!("line map must have `:spans`"))?
+            .0;
+
+        if let Some(alignment) =
+            crate::options::get::<crate::terminal::Alignment>(term, atoms::alignment())?
+        {
+            line = line.alignment(alignment.0);
+        }
+
+        if let Some(style) = crate::options::get::<crate::style::Style>(term, atoms::style())? {
+            line = line.style(style.0);
+        }
+
+        Ok(Line(line))
+    }
+}
+
+/// A binary (split on newlines), a list of lines, `{:markup, binary}` (see
+/// `crate::markup`), `{:ansi, binary}` for output containing SGR escape
+/// sequences (e.g. from `IO.ANSI` or a shell command), which are turned into
+/// styles instead of being printed, or `{:code, language, source, options}`
+/// for syntax-highlighted source (see `crate::highlight`).
+pub(crate) struct Text(pub ratatui::text::Text<'static>);
+
+impl Decoder<'_> for Text {
+    fn decode(term: Term<'_>) -> NifResult<Self> {
+        if term.is_binary() {
+            return Ok(Text(ratatui::text::Text::from(term.decode::<String>()?)));
+        }
+
+        if term.is_list() {
+            let lines: Vec<Line> = term.decode()?;
+            return Ok(Text(ratatui::text::Text::from(
+                lines.into_iter().map(|l| l.0).collect::<Vec<_>>(),
+            )));
+        }
+
+        if let Some(text) = markup(term)? {
+            return Ok(Text(text));
+        }
+
+        if let Some(text) = crate::highlight::code(term)? {
+            return Ok(Text(text));
+        }
+
+        if let Ok((tag, content)) = term.decode::<(rustler::Atom, Binary)>() {
+            if tag == atoms::ansi() {
+                return content
+                    .as_slice()
+                    .into_text()
+                    .map(Text)
+                    .map_err(|e| nif_error!(e));
+            }
+        }
+
+        Err(nif_error!(
+            "text must be a binary, a list of lines, `{:markup, binary}`, `{:ansi, binary}` or `{:code, language, source, options}`"
+        ))
+    }
+}
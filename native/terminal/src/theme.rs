@@ -0,0 +1,230 @@
+//! Named styles registered once from Elixir. Anywhere a style is accepted, an
+//! atom like `:error` is resolved against the active theme instead.
+//!
+//! Widget styles set through a `*_style` NIF, a block's styles and its
+//! default title style are recorded by name in the resource's `Themed` and
+//! resolved again every time the widget is drawn, so activating another
+//! theme restyles widgets that were already built. Styles inside text
+//! (spans, lines, titles, items, cells) and in widget data (datasets, axes,
+//! bars, calendar events, canvas labels) are resolved once, when they are
+//! decoded. There is one active theme per VM, shared by every terminal.
+
+use crate::terminal::nif_error;
+use ratatui::style::Style;
+use rustler::{NifResult, ResourceArc, Term};
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+
+pub(crate) struct ThemeResource(pub RwLock<HashMap<String, Style>>);
+
+impl rustler::Resource for ThemeResource {}
+
+static ACTIVE_THEME: Mutex<Option<ResourceArc<ThemeResource>>> = Mutex::new(None);
+
+/// Looks up a style by name in the active theme.
+pub(crate) fn lookup(name: &str) -> Option<Style> {
+    let active = ACTIVE_THEME.lock().unwrap();
+    let theme = active.as_ref()?;
+    let styles = theme.0.read().unwrap();
+    styles.get(name).copied()
+}
+
+type Restyle<W> = Box<dyn Fn(W) -> W + Send + Sync>;
+
+/// The theme style names a widget was styled with, each with the setter that
+/// applies it, keyed so that calling a setter again replaces its entry.
+pub(crate) struct Themed<W>(Mutex<Vec<(&'static str, Restyle<W>)>>);
+
+impl<W> Default for Themed<W> {
+    fn default() -> Self {
+        Themed(Mutex::new(vec![]))
+    }
+}
+
+impl<W: 'static> Themed<W> {
+    /// Starts from the block a widget was built with. If any of the block's
+    /// styles came from the theme, drawing re-attaches a restyled copy of it.
+    pub(crate) fn with_block(
+        block: &ratatui::widgets::Block<'static>,
+        block_theme: Themed<ratatui::widgets::Block<'static>>,
+        attach: fn(W, ratatui::widgets::Block<'static>) -> W,
+    ) -> Self {
+        let themed = Themed::default();
+
+        if !block_theme.0.lock().unwrap().is_empty() {
+            let block = block.clone();
+            themed.put(
+                "block",
+                Some(Box::new(move |widget| {
+                    attach(widget, block_theme.apply(block.clone()))
+                })),
+            );
+        }
+
+        themed
+    }
+
+    /// Records `style`'s theme name for `setter`, or forgets an earlier one
+    /// when `style` was given directly.
+    pub(crate) fn set(
+        &self,
+        setter: &'static str,
+        style: &crate::style::Style,
+        apply: fn(W, Style) -> W,
+    ) {
+        self.put(
+            setter,
+            style.1.map(|name| -> Restyle<W> {
+                Box::new(move |widget| match lookup(name) {
+                    Some(style) => apply(widget, style),
+                    None => widget,
+                })
+            }),
+        );
+    }
+
+    /// Records `restyle` for `setter`, replacing any earlier entry; `None`
+    /// only removes it.
+    pub(crate) fn put(&self, setter: &'static str, restyle: Option<Restyle<W>>) {
+        let mut entries = self.0.lock().unwrap();
+        entries.retain(|(key, _)| *key != setter);
+        entries.extend(restyle.map(|restyle| (setter, restyle)));
+    }
+
+    /// Moves the entries out, for a block handed over to a widget.
+    pub(crate) fn take(&self) -> Self {
+        Themed(Mutex::new(std::mem::take(&mut *self.0.lock().unwrap())))
+    }
+
+    /// Re-resolves every recorded name against the active theme.
+    pub(crate) fn apply(&self, widget: W) -> W {
+        self.0
+            .lock()
+            .unwrap()
+            .iter()
+            .fold(widget, |widget, (_, restyle)| restyle(widget))
+    }
+
+    /// `apply` on a builder resource's widget, in place, before it is drawn.
+    pub(crate) fn restyle(&self, widget: &Mutex<Option<W>>) {
+        let mut widget = widget.lock().unwrap();
+        if let Some(inner) = widget.take() {
+            *widget = Some(self.apply(inner));
+        }
+    }
+}
+
+/// A map or keyword list of style name to style.
+fn decode_styles(term: Term) -> NifResult<HashMap<String, Style>> {
+    let pairs: Vec<(Term, Term)> = if term.is_map() {
+        rustler::types::map::MapIterator::new(term)
+            .ok_or_else(|| nif_error!("theme must be a map or keyword list"))?
+            .collect()
+    } else {
+        term.decode()?
+    };
+
+    pairs
+        .into_iter()
+        .map(|(name, style)| {
+            let name = name
+                .atom_to_string()
+                .map_err(|_| nif_error!("theme style names must be atoms"))?;
+            let style: crate::style::Style = style.decode()?;
+            Ok((name, style.0))
+        })
+        .collect()
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn theme_new(styles: Term) -> NifResult<ResourceArc<ThemeResource>> {
+    Ok(ResourceArc::new(ThemeResource(RwLock::new(decode_styles(
+        styles,
+    )?))))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn theme_put(
+    theme: ResourceArc<ThemeResource>,
+    name: Term,
+    style: crate::style::Style,
+) -> NifResult<ResourceArc<ThemeResource>> {
+    let name = name
+        .atom_to_string()
+        .map_err(|_| nif_error!("theme style names must be atoms"))?;
+    theme.0.write().unwrap().insert(name, style.0);
+    Ok(theme)
+}
+
+/// Makes `theme` the one named styles are resolved against from now on,
+/// including by widgets that are already built, on their next draw.
+#[rustler::nif(schedule = "DirtyIo")]
+fn theme_activate(theme: ResourceArc<ThemeResource>) {
+    *ACTIVE_THEME.lock().unwrap() = Some(theme);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+    use ratatui::widgets::Block;
+
+    fn red(block: Block<'static>) -> Block<'static> {
+        block.style(Style::new().fg(Color::Red))
+    }
+
+    fn blue(block: Block<'static>) -> Block<'static> {
+        block.style(Style::new().fg(Color::Blue))
+    }
+
+    #[test]
+    fn a_setter_replaces_its_own_entry() {
+        let themed = Themed::default();
+        themed.put("style", Some(Box::new(red)));
+        themed.put("style", Some(Box::new(blue)));
+
+        assert_eq!(themed.apply(Block::new()), blue(Block::new()));
+    }
+
+    #[test]
+    fn a_direct_style_forgets_the_name() {
+        let themed = Themed::default();
+        themed.put("style", Some(Box::new(red)));
+        themed.set(
+            "style",
+            &crate::style::Style(Style::new().fg(Color::Green), None),
+            Block::style,
+        );
+
+        assert_eq!(themed.apply(Block::new()), Block::new());
+    }
+
+    #[test]
+    fn a_name_missing_from_the_theme_keeps_the_built_style() {
+        let themed = Themed::default();
+        themed.set(
+            "style",
+            &crate::style::Style(Style::new(), Some("missing")),
+            Block::style,
+        );
+
+        assert_eq!(themed.apply(red(Block::new())), red(Block::new()));
+    }
+
+    #[test]
+    fn a_widget_restyles_the_block_it_was_built_with() {
+        let block_theme = Themed::default();
+        block_theme.put("style", Some(Box::new(blue)));
+
+        let themed = Themed::with_block(&Block::bordered(), block_theme, |_, block| block);
+
+        assert_eq!(themed.apply(Block::new()), blue(Block::bordered()));
+    }
+
+    #[test]
+    fn a_block_without_theme_names_is_not_reattached() {
+        let themed = Themed::with_block(&Block::bordered(), Themed::default(), |_, block| block);
+
+        assert_eq!(themed.apply(Block::new()), Block::new());
+    }
+}
@@ -0,0 +1,150 @@
+//! Measuring strings the way the terminal lays them out: one grapheme at a
+//! time, with East Asian wide characters and emoji taking two cells. This is
+//! the same measure ratatui uses when it writes text into a buffer.
+
+use ratatui::layout::Alignment;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+fn width(s: &str) -> usize {
+    s.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+/// Returns the longest prefix of `s` that fits in `max_width` cells.
+fn take_width(s: &str, max_width: usize) -> &str {
+    let mut used = 0;
+
+    for (offset, grapheme) in s.grapheme_indices(true) {
+        used += grapheme.width();
+        if used > max_width {
+            return &s[..offset];
+        }
+    }
+
+    s
+}
+
+/// Cuts `text` down to `max_width` cells, ending it with `ellipsis` when
+/// anything was removed. Text that already fits is returned unchanged.
+fn truncate(text: &str, max_width: usize, ellipsis: &str) -> String {
+    if width(text) <= max_width {
+        return text.to_string();
+    }
+
+    let ellipsis = take_width(ellipsis, max_width);
+    let mut truncated = take_width(text, max_width - width(ellipsis)).to_string();
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+/// Pads `text` with spaces to `target_width` cells. Text that is already
+/// wider is returned unchanged; combine with `truncate` to get an exact
+/// width.
+fn pad(text: &str, target_width: usize, alignment: Alignment) -> String {
+    let padding = target_width.saturating_sub(width(text));
+
+    let (left, right) = match alignment {
+        Alignment::Left => (0, padding),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+        Alignment::Right => (padding, 0),
+    };
+
+    format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn unicode_width(text: &str) -> usize {
+    width(text)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn unicode_truncate(text: &str, max_width: usize, ellipsis: &str) -> String {
+    truncate(text, max_width, ellipsis)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+fn unicode_pad(text: &str, target_width: usize, alignment: crate::terminal::Alignment) -> String {
+    pad(text, target_width, alignment.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAMILY: &str = "👨\u{200d}👩\u{200d}👧";
+
+    #[test]
+    fn width_counts_wide_characters_as_two_cells() {
+        assert_eq!(width("abc"), 3);
+        assert_eq!(width("日本語"), 6);
+        assert_eq!(width("e\u{301}"), 1);
+        assert_eq!(width(""), 0);
+    }
+
+    #[test]
+    fn width_counts_a_zwj_sequence_once() {
+        assert_eq!(width(FAMILY), 2);
+        assert_eq!(width(&format!("a{FAMILY}b")), 4);
+    }
+
+    #[test]
+    fn take_width_stops_before_a_wide_character_that_would_overflow() {
+        assert_eq!(take_width("a日本", 2), "a");
+        assert_eq!(take_width("a日本", 3), "a日");
+        assert_eq!(take_width("日本", 0), "");
+    }
+
+    #[test]
+    fn take_width_never_splits_a_grapheme() {
+        assert_eq!(take_width(FAMILY, 1), "");
+        assert_eq!(take_width(FAMILY, 2), FAMILY);
+        assert_eq!(take_width("e\u{301}x", 1), "e\u{301}");
+    }
+
+    #[test]
+    fn truncate_leaves_text_that_fits() {
+        assert_eq!(truncate("日本", 4, "…"), "日本");
+    }
+
+    #[test]
+    fn truncate_does_not_overflow_at_a_wide_character() {
+        let truncated = truncate("日本語", 4, "…");
+
+        assert_eq!(truncated, "日…");
+        assert!(width(&truncated) <= 4);
+    }
+
+    #[test]
+    fn truncate_cuts_the_ellipsis_when_it_is_wider_than_max_width() {
+        assert_eq!(truncate("abcdef", 2, "..."), "..");
+        assert_eq!(truncate("abcdef", 1, "……"), "…");
+        // a two-cell ellipsis can't fit in one cell, so it is dropped and the
+        // text gets the cell instead
+        assert_eq!(truncate("abcdef", 1, "〜"), "a");
+    }
+
+    #[test]
+    fn truncate_to_zero_width_is_empty() {
+        assert_eq!(truncate("abc", 0, "…"), "");
+    }
+
+    #[test]
+    fn truncate_keeps_zwj_sequences_whole() {
+        assert_eq!(
+            truncate(&format!("{FAMILY}{FAMILY}"), 3, "…"),
+            format!("{FAMILY}…")
+        );
+    }
+
+    #[test]
+    fn pad_aligns_by_cells() {
+        assert_eq!(pad("日本", 7, Alignment::Left), "日本   ");
+        assert_eq!(pad("日本", 7, Alignment::Center), " 日本  ");
+        assert_eq!(pad("日本", 7, Alignment::Right), "   日本");
+    }
+
+    #[test]
+    fn pad_leaves_wider_text_unchanged() {
+        assert_eq!(pad("日本語", 4, Alignment::Center), "日本語");
+    }
+}